@@ -0,0 +1,185 @@
+use anchor_lang::prelude::*;
+use crate::{
+    GameConfig, FeeVault, Match, PlayerEntry, Tournament, MatchStatus, TournamentStatus,
+    ErrorCode, seeds, utils::bracket::{first_advances, Contender}
+};
+
+/// Permissionless crank: decides every pairing of the current round
+/// Remaining accounts: [match, entry_a, entry_b] for each pairing, in bracket order
+#[derive(Accounts)]
+pub struct AdvanceTournament<'info> {
+    #[account(
+        mut,
+        seeds = [seeds::GAME_CONFIG],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [seeds::TOURNAMENT, tournament.tournament_id.to_le_bytes().as_ref()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::InProgress @ ErrorCode::InvalidTournamentStatus,
+        constraint = tournament.round_match_ids.len() == tournament.pairings_in_round() @ ErrorCode::RoundNotFinished
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// CHECK: Tournament escrow vault
+    #[account(
+        mut,
+        seeds = [seeds::VAULT, tournament.key().as_ref()],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    /// Protocol fee vault (required when a protocol fee is charged)
+    #[account(
+        mut,
+        seeds = [seeds::FEE_VAULT],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Option<Account<'info, FeeVault>>,
+
+    pub caller: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AdvanceTournament>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let tournament = &mut ctx.accounts.tournament;
+    let clock = Clock::get()?;
+
+    let round = tournament.current_round;
+    let alive = tournament.alive_in_bracket();
+    require!(
+        ctx.remaining_accounts.len() == tournament.round_match_ids.len() * 3,
+        ErrorCode::InvalidBracketAccount
+    );
+
+    let mut losers = Vec::with_capacity(tournament.round_match_ids.len());
+
+    for (pairing, accounts) in ctx.remaining_accounts.chunks(3).enumerate() {
+        let match_id = tournament.round_match_ids[pairing];
+        let slots = [alive[2 * pairing], alive[2 * pairing + 1]];
+
+        // Verify the child match
+        let match_info = &accounts[0];
+        require_keys_eq!(*match_info.owner, crate::ID, ErrorCode::InvalidBracketAccount);
+        let child = Match::try_deserialize(&mut &match_info.data.borrow()[..])?;
+        let expected = Pubkey::create_program_address(
+            &[seeds::MATCH, match_id.to_le_bytes().as_ref(), &[child.bump]],
+            &crate::ID,
+        ).map_err(|_| ErrorCode::InvalidBracketAccount)?;
+        require!(
+            match_info.key() == expected && child.match_id == match_id,
+            ErrorCode::InvalidBracketAccount
+        );
+
+        // A match nobody predicted in is decided on seed once its window closes
+        let resolved = match child.status {
+            MatchStatus::Completed => true,
+            MatchStatus::Open | MatchStatus::Cancelled
+                if child.start_price.is_none() &&
+                   clock.unix_timestamp > child.created_at + child.prediction_window => false,
+            _ => return err!(ErrorCode::RoundNotFinished),
+        };
+
+        // Verify both entries and build contenders
+        let mut contenders = Vec::with_capacity(2);
+        for (info, &slot) in accounts[1..].iter().zip(slots.iter()) {
+            require_keys_eq!(*info.owner, crate::ID, ErrorCode::InvalidBracketAccount);
+            let entry = PlayerEntry::try_deserialize(&mut &info.data.borrow()[..])?;
+            require!(
+                entry.match_account == match_info.key() &&
+                entry.player == tournament.players[slot as usize],
+                ErrorCode::InvalidBracketAccount
+            );
+            contenders.push(Contender {
+                seed: tournament.seed_of(slot),
+                prediction: entry.prediction,
+                locked_at: entry.prediction_locked_at,
+            });
+        }
+
+        let loser = if first_advances(&contenders[0], &contenders[1], resolved, child.winning_side) {
+            slots[1]
+        } else {
+            slots[0]
+        };
+        losers.push(tournament.players[loser as usize]);
+        tournament.eliminated_in[loser as usize] = round;
+    }
+
+    tournament.round_match_ids.clear();
+
+    emit!(TournamentRoundAdvanced {
+        tournament_id: tournament.tournament_id,
+        round,
+        eliminated: losers,
+    });
+
+    if round < tournament.total_rounds {
+        tournament.current_round = round + 1;
+        return Ok(());
+    }
+
+    // Final decided: take the protocol fee once and lock in the prize pool
+    let protocol_fee = (tournament.total_pot as u128 * tournament.fee_bps(config.protocol_fee_bps) as u128 / 10000) as u64;
+    tournament.prize_pool = tournament.total_pot - protocol_fee;
+    tournament.status = TournamentStatus::Completed;
+    tournament.completed_at = Some(clock.unix_timestamp);
+
+    if protocol_fee > 0 {
+        let fee_vault = ctx.accounts.fee_vault.as_ref().ok_or(ErrorCode::InvalidFeeVault)?;
+        let tournament_key = tournament.key();
+        let vault_seeds = &[
+            seeds::VAULT,
+            tournament_key.as_ref(),
+            &[ctx.bumps.vault],
+        ];
+        let signer_seeds = &[&vault_seeds[..]];
+
+        anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: fee_vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            protocol_fee,
+        )?;
+    }
+
+    config.total_volume = config.total_volume
+        .checked_add(tournament.total_pot)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    let champion = tournament.alive_in_bracket()[0];
+
+    emit!(TournamentCompleted {
+        tournament_id: tournament.tournament_id,
+        champion: tournament.players[champion as usize],
+        prize_pool: tournament.prize_pool,
+        protocol_fee,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct TournamentRoundAdvanced {
+    pub tournament_id: u64,
+    pub round: u8,
+    pub eliminated: Vec<Pubkey>,
+}
+
+#[event]
+pub struct TournamentCompleted {
+    pub tournament_id: u64,
+    pub champion: Pubkey,
+    pub prize_pool: u64,
+    pub protocol_fee: u64,
+}
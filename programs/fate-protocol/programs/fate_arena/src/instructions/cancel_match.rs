@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::{
-    GameConfig, Match, MatchStatus, ErrorCode, seeds
+    GameConfig, Match, RoleAssignment, MatchStatus, Role, ErrorCode, seeds
 };
 
 #[derive(Accounts)]
@@ -19,14 +19,29 @@ pub struct CancelMatch<'info> {
     )]
     pub match_account: Account<'info, Match>,
 
+    /// Operator role of the signer (not needed for creator or config authority)
     #[account(
-        constraint = authority.key() == match_account.creator ||
-                     authority.key() == config.authority @ ErrorCode::Unauthorized
+        seeds = [seeds::ROLE, Role::Operator.seed(), authority.key().as_ref()],
+        bump = role_assignment.bump
     )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    /// Match creator, config authority or operator
     pub authority: Signer<'info>,
 }
 
 pub fn handler(ctx: Context<CancelMatch>) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    require!(
+        authority == ctx.accounts.match_account.creator ||
+            ctx.accounts.config.has_role(
+                &authority,
+                Role::Operator,
+                ctx.accounts.role_assignment.as_deref()
+            ),
+        ErrorCode::Unauthorized
+    );
+
     let match_account = &mut ctx.accounts.match_account;
 
     // Update status
@@ -38,8 +53,7 @@ pub fn handler(ctx: Context<CancelMatch>) -> Result<()> {
         cancelled_by: ctx.accounts.authority.key(),
     });
 
-    // Note: Players need to call a separate refund instruction
-    // or we handle refunds in claim_winnings for cancelled matches
+    // Players recover their stake through claim_refund
 
     Ok(())
 }
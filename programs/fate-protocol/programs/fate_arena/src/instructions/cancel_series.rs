@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::{
+    Series, SeriesStatus, ErrorCode, seeds
+};
+
+/// Creator may cancel before an opponent joins and reclaim the stake
+/// via claim_series_prize
+#[derive(Accounts)]
+pub struct CancelSeries<'info> {
+    #[account(
+        mut,
+        seeds = [seeds::SERIES, series.series_id.to_le_bytes().as_ref()],
+        bump = series.bump,
+        constraint = series.status == SeriesStatus::Open @ ErrorCode::InvalidSeriesStatus,
+        constraint = series.player_a == creator.key() @ ErrorCode::Unauthorized
+    )]
+    pub series: Account<'info, Series>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CancelSeries>) -> Result<()> {
+    let series = &mut ctx.accounts.series;
+
+    series.status = SeriesStatus::Cancelled;
+
+    emit!(SeriesCancelled {
+        series_id: series.series_id,
+        wins_a: series.wins_a,
+        wins_b: series.wins_b,
+        refund_total: series.total_pot,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct SeriesCancelled {
+    pub series_id: u64,
+    pub wins_a: u8,
+    pub wins_b: u8,
+    pub refund_total: u64,
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::{
+    Tournament, TournamentStatus, ErrorCode, seeds
+};
+
+/// Creator may cancel during registration; anyone may once registration
+/// closed without filling. Players reclaim entry fees via claim_tournament_prize
+#[derive(Accounts)]
+pub struct CancelTournament<'info> {
+    #[account(
+        mut,
+        seeds = [seeds::TOURNAMENT, tournament.tournament_id.to_le_bytes().as_ref()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::Registration @ ErrorCode::InvalidTournamentStatus
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    pub caller: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CancelTournament>) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let clock = Clock::get()?;
+
+    let expired = clock.unix_timestamp > tournament.registration_ends_at && !tournament.is_full();
+    require!(
+        expired || ctx.accounts.caller.key() == tournament.creator,
+        ErrorCode::Unauthorized
+    );
+
+    tournament.status = TournamentStatus::Cancelled;
+
+    emit!(TournamentCancelled {
+        tournament_id: tournament.tournament_id,
+        registered: tournament.players.len() as u8,
+        refund_total: tournament.total_pot,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct TournamentCancelled {
+    pub tournament_id: u64,
+    pub registered: u8,
+    pub refund_total: u64,
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::{Jackpot, Match, PlayerEntry, ErrorCode, seeds};
+
+#[derive(Accounts)]
+pub struct ClaimJackpot<'info> {
+    #[account(
+        mut,
+        seeds = [seeds::JACKPOT],
+        bump = jackpot.bump
+    )]
+    pub jackpot: Account<'info, Jackpot>,
+
+    #[account(
+        seeds = [seeds::MATCH, match_account.match_id.to_le_bytes().as_ref()],
+        bump = match_account.bump
+    )]
+    pub match_account: Account<'info, Match>,
+
+    #[account(
+        mut,
+        seeds = [
+            seeds::PLAYER_ENTRY,
+            match_account.key().as_ref(),
+            player.key().as_ref()
+        ],
+        bump = player_entry.bump,
+        constraint = player_entry.jackpot_award > 0 @ ErrorCode::NoJackpotAward
+    )]
+    pub player_entry: Account<'info, PlayerEntry>,
+
+    #[account(mut)]
+    pub player: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ClaimJackpot>) -> Result<()> {
+    let jackpot = &mut ctx.accounts.jackpot;
+    let player_entry = &mut ctx.accounts.player_entry;
+    let amount = player_entry.jackpot_award;
+
+    // Awards were set aside from the balance above rent when they were won
+    let jackpot_info = jackpot.to_account_info();
+    let balance = jackpot_info.lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(jackpot_info.data_len()));
+    jackpot.record_claim(amount, balance)?;
+
+    **jackpot_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.player.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    player_entry.jackpot_award = 0;
+
+    emit!(JackpotClaimed {
+        match_id: ctx.accounts.match_account.match_id,
+        player: ctx.accounts.player.key(),
+        amount,
+        total_claimed: jackpot.total_claimed,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct JackpotClaimed {
+    pub match_id: u64,
+    pub player: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+}
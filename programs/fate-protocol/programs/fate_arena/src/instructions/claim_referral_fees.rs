@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::{Referrer, ErrorCode, seeds};
+
+#[derive(Accounts)]
+pub struct ClaimReferralFees<'info> {
+    #[account(
+        mut,
+        seeds = [seeds::REFERRER, referrer.key().as_ref()],
+        bump = referrer_account.bump
+    )]
+    pub referrer_account: Account<'info, Referrer>,
+
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ClaimReferralFees>) -> Result<()> {
+    let referrer_account = &mut ctx.accounts.referrer_account;
+
+    // Accrued fees sit on the referrer account above its rent-exempt minimum
+    let referrer_info = referrer_account.to_account_info();
+    let available = referrer_info.lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(referrer_info.data_len()));
+    let amount = referrer_account.claimable().min(available);
+    require!(amount > 0, ErrorCode::NoReferralFees);
+
+    **referrer_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.referrer.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    referrer_account.total_claimed = referrer_account.total_claimed.checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(ReferralFeesClaimed {
+        referrer: referrer_account.referrer,
+        amount,
+        total_claimed: referrer_account.total_claimed,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ReferralFeesClaimed {
+    pub referrer: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+}
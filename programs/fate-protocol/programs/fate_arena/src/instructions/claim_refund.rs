@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::{
+    Match, PlayerEntry, UserProfile, MatchStatus, ErrorCode, seeds, utils::escrow::Escrow
+};
+
+#[derive(Accounts)]
+pub struct ClaimRefund<'info> {
+    #[account(
+        mut,
+        seeds = [seeds::MATCH, match_account.match_id.to_le_bytes().as_ref()],
+        bump = match_account.bump,
+        constraint = match_account.status == MatchStatus::Cancelled @ ErrorCode::InvalidMatchStatus
+    )]
+    pub match_account: Account<'info, Match>,
+
+    #[account(
+        mut,
+        close = player,
+        seeds = [
+            seeds::PLAYER_ENTRY,
+            match_account.key().as_ref(),
+            player.key().as_ref()
+        ],
+        bump = player_entry.bump,
+        constraint = !player_entry.claimed @ ErrorCode::AlreadyClaimed
+    )]
+    pub player_entry: Account<'info, PlayerEntry>,
+
+    /// CHECK: Match vault
+    #[account(
+        mut,
+        seeds = [seeds::VAULT, match_account.key().as_ref()],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    /// Player's profile (required when the entry counts as open on it)
+    #[account(
+        mut,
+        seeds = [seeds::USER_PROFILE, player.key().as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Option<Account<'info, UserProfile>>,
+
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Match's stake mint (token matches only)
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Match's token vault (token matches only)
+    #[account(mut)]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Player's token account (token matches only)
+    #[account(mut)]
+    pub player_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ClaimRefund<'info>>) -> Result<()> {
+    let match_account = &mut ctx.accounts.match_account;
+    let amount = ctx.accounts.player_entry.amount_staked;
+
+    Escrow::new(
+        match_account,
+        &ctx.accounts.vault,
+        ctx.bumps.vault,
+        ctx.accounts.mint.as_ref(),
+        ctx.accounts.vault_token_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.system_program,
+    )?.with_hook_accounts(ctx.remaining_accounts).pay(
+        &ctx.accounts.player.to_account_info(),
+        ctx.accounts.player_token_account.as_ref(),
+        amount,
+    )?;
+    match_account.record_payout(amount)?;
+    if ctx.accounts.player_entry.in_open_entries {
+        let user_profile = ctx.accounts.user_profile.as_mut().ok_or(ErrorCode::InvalidUserProfile)?;
+        user_profile.settle_entry(&mut ctx.accounts.player_entry);
+    }
+
+    emit!(RefundClaimed {
+        match_id: match_account.match_id,
+        player: ctx.accounts.player.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RefundClaimed {
+    pub match_id: u64,
+    pub player: Pubkey,
+    pub amount: u64,
+}
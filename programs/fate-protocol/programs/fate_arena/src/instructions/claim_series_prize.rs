@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use crate::{
+    Series, SeriesStatus, ErrorCode, seeds
+};
+
+#[derive(Accounts)]
+pub struct ClaimSeriesPrize<'info> {
+    #[account(
+        mut,
+        seeds = [seeds::SERIES, series.series_id.to_le_bytes().as_ref()],
+        bump = series.bump,
+        constraint = series.status == SeriesStatus::Completed ||
+                     series.status == SeriesStatus::Cancelled @ ErrorCode::InvalidSeriesStatus,
+        constraint = series.is_player(&player.key()) @ ErrorCode::NotSeriesPlayer
+    )]
+    pub series: Account<'info, Series>,
+
+    /// CHECK: Series escrow vault
+    #[account(
+        mut,
+        seeds = [seeds::VAULT, series.key().as_ref()],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimSeriesPrize>) -> Result<()> {
+    let series = &mut ctx.accounts.series;
+    let player = ctx.accounts.player.key();
+
+    let is_player_a = player == series.player_a;
+    let claimed = if is_player_a { series.claimed_a } else { series.claimed_b };
+    require!(!claimed, ErrorCode::AlreadyClaimed);
+
+    let is_refund = series.status == SeriesStatus::Cancelled;
+    let amount = if is_refund {
+        series.entry_fee
+    } else if series.winner == Some(player) {
+        series.prize_pool
+    } else {
+        0
+    };
+    require!(amount > 0, ErrorCode::NoWinnings);
+
+    if is_player_a {
+        series.claimed_a = true;
+    } else {
+        series.claimed_b = true;
+    }
+
+    let series_key = series.key();
+    let vault_seeds = &[
+        seeds::VAULT,
+        series_key.as_ref(),
+        &[ctx.bumps.vault],
+    ];
+    let signer_seeds = &[&vault_seeds[..]];
+
+    anchor_lang::system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.player.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    emit!(SeriesPrizeClaimed {
+        series_id: series.series_id,
+        player,
+        amount,
+        is_refund,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct SeriesPrizeClaimed {
+    pub series_id: u64,
+    pub player: Pubkey,
+    pub amount: u64,
+    pub is_refund: bool,
+}
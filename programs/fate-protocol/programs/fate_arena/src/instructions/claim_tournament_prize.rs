@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use crate::{
+    Tournament, TournamentStatus, ErrorCode, seeds
+};
+
+#[derive(Accounts)]
+pub struct ClaimTournamentPrize<'info> {
+    #[account(
+        mut,
+        seeds = [seeds::TOURNAMENT, tournament.tournament_id.to_le_bytes().as_ref()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::Completed ||
+                     tournament.status == TournamentStatus::Cancelled @ ErrorCode::InvalidTournamentStatus
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// CHECK: Tournament escrow vault
+    #[account(
+        mut,
+        seeds = [seeds::VAULT, tournament.key().as_ref()],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimTournamentPrize>) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let player = ctx.accounts.player.key();
+
+    let index = tournament.player_index(&player)
+        .ok_or(ErrorCode::PlayerNotInTournament)?;
+    require!(!tournament.has_claimed(index), ErrorCode::AlreadyClaimed);
+
+    let is_refund = tournament.status == TournamentStatus::Cancelled;
+    let amount = if is_refund {
+        tournament.entry_fee
+    } else {
+        (tournament.prize_pool as u128 * tournament.placement_bps(index) as u128 / 10000) as u64
+    };
+    require!(amount > 0, ErrorCode::NoWinnings);
+
+    tournament.claimed_mask |= 1 << index;
+
+    let tournament_key = tournament.key();
+    let vault_seeds = &[
+        seeds::VAULT,
+        tournament_key.as_ref(),
+        &[ctx.bumps.vault],
+    ];
+    let signer_seeds = &[&vault_seeds[..]];
+
+    anchor_lang::system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.player.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    emit!(TournamentPrizeClaimed {
+        tournament_id: tournament.tournament_id,
+        player,
+        amount,
+        eliminated_in: tournament.eliminated_in[index],
+        is_refund,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct TournamentPrizeClaimed {
+    pub tournament_id: u64,
+    pub player: Pubkey,
+    pub amount: u64,
+    /// Round the player was knocked out in (0 = champion)
+    pub eliminated_in: u8,
+    pub is_refund: bool,
+}
@@ -1,11 +1,17 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::{
-    GameConfig, Match, PlayerEntry, UserProfile, MatchStatus,
-    ErrorCode, seeds, constants::*
+    GameConfig, FeeVault, Match, PlayerEntry, UserProfile, Referrer, Jackpot, MatchStatus,
+    PredictionSide, JackpotTrigger, ErrorCode, seeds, constants::*,
+    utils::elimination::rounds_survived, utils::escrow::Escrow,
+    utils::fee_tiers::{tier_discount_bps, discounted_fee_bps},
+    utils::jackpot::{jackpot_trigger, jackpot_award}, utils::sealed::sealed_refund,
+    utils::pyth::stake_weighted_share
 };
 
 /// Remaining accounts should be all PlayerEntry accounts for this match
-/// This is needed to count total winners
+/// This is needed to count total winners on matches created before sides were
+/// counted on-chain, and to weight USD-pegged payouts by stake
 #[derive(Accounts)]
 pub struct ClaimWinnings<'info> {
     #[account(
@@ -15,6 +21,7 @@ pub struct ClaimWinnings<'info> {
     pub config: Account<'info, GameConfig>,
 
     #[account(
+        mut,
         seeds = [seeds::MATCH, match_account.match_id.to_le_bytes().as_ref()],
         bump = match_account.bump,
         constraint = match_account.status == MatchStatus::Completed @ ErrorCode::InvalidMatchStatus
@@ -48,52 +55,100 @@ pub struct ClaimWinnings<'info> {
     )]
     pub vault: AccountInfo<'info>,
 
-    /// CHECK: Treasury account
+    /// Protocol fee vault (required when this claim pays a protocol fee)
     #[account(
         mut,
-        constraint = treasury.key() == config.treasury
+        seeds = [seeds::FEE_VAULT],
+        bump = fee_vault.bump
     )]
-    pub treasury: AccountInfo<'info>,
+    pub fee_vault: Option<Account<'info, FeeVault>>,
 
     #[account(mut)]
     pub player: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Match's stake mint (token matches only)
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Match's token vault (token matches only)
+    #[account(mut)]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Player's token account (token matches only)
+    #[account(mut)]
+    pub player_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Fee vault's token account for the match mint (token matches only)
+    #[account(mut)]
+    pub fee_vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// CHECK: Match creator, paid the creator fee (required if the match has one)
+    #[account(
+        mut,
+        constraint = creator.key() == match_account.creator @ ErrorCode::InvalidCreatorAccount
+    )]
+    pub creator: Option<AccountInfo<'info>>,
+
+    /// Creator's token account for the match mint (token matches with a creator fee)
+    #[account(mut)]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Referral balance of the player's referrer (required if they have one)
+    #[account(
+        mut,
+        seeds = [seeds::REFERRER, referrer_account.referrer.as_ref()],
+        bump = referrer_account.bump
+    )]
+    pub referrer_account: Option<Account<'info, Referrer>>,
+
+    /// Progressive jackpot (required if this claim hits it while it is enabled)
+    #[account(
+        mut,
+        seeds = [seeds::JACKPOT],
+        bump = jackpot.bump
+    )]
+    pub jackpot: Option<Account<'info, Jackpot>>,
 }
 
-pub fn handler(ctx: Context<ClaimWinnings>) -> Result<()> {
-    let match_account = &ctx.accounts.match_account;
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ClaimWinnings<'info>>) -> Result<()> {
+    let match_account = &mut ctx.accounts.match_account;
     let player_entry = &mut ctx.accounts.player_entry;
     let user_profile = &mut ctx.accounts.user_profile;
     let config = &ctx.accounts.config;
+    let fee_bps = match_account.fee_bps(config.protocol_fee_bps);
 
     // Check if this is a refund case (no winners, prices equal)
-    let is_refund = match_account.winning_side.is_none();
+    let is_refund = match_account.is_refund();
 
     // Check if player won (or getting refund)
-    let is_winner = if is_refund {
-        // Everyone gets refund
-        true
+    let is_winner = is_refund ||
+        match_account.is_winning_entry(player_entry.prediction, player_entry.player_index);
+
+    // Winners pay the match fee less their fee-tier discount, judged on the
+    // level and rolling volume they had before this claim
+    let now = Clock::get()?.unix_timestamp;
+    let discount_bps = tier_discount_bps(
+        &config.fee_tiers,
+        user_profile.level,
+        user_profile.rolling_volume(now),
+    );
+    let player_fee_bps = discounted_fee_bps(fee_bps, discount_bps);
+
+    // Normal matches split the pool between everyone on the winning side
+    let winner_count = if is_refund || match_account.is_elimination_mode() || match_account.is_closest_price_mode() {
+        0
+    } else if let Some(PredictionSide::Bucket(index)) = match_account.winning_side {
+        // Range matches count picks per bucket on-chain
+        match_account.bucket_counts[index as usize] as u64
+    } else if let Some(count) = match_account.winning_side.and_then(|side| match_account.side_count(side)) {
+        // So do two-sided matches created since sides were counted
+        count as u64
     } else {
-        // Check if prediction matches winning side
-        let winning_side = match_account.winning_side.unwrap();
-        player_entry.prediction == Some(winning_side)
-    };
-
-    // Calculate winnings based on refund vs winner scenario
-    let per_winner_amount = if is_refund {
-        // Refund case: everyone gets their entry fee back (no protocol fee)
-        msg!("REFUND: Prices equal, returning entry fee");
-        player_entry.amount_staked
-    } else {
-        // Normal winner case
-        let protocol_fee = match_account.calculate_protocol_fee(config.protocol_fee_bps);
-        let prize_pool = match_account.calculate_prize_pool(config.protocol_fee_bps);
-
-        // We need to count winners from remaining_accounts
-        let mut winner_count = 0u64;
-
         // Count winners from remaining accounts
+        let mut winner_count = 0u64;
         for account_info in ctx.remaining_accounts.iter() {
             if let Ok(entry) = PlayerEntry::try_deserialize(&mut &account_info.data.borrow()[..]) {
                 if entry.match_account == match_account.key() &&
@@ -102,25 +157,164 @@ pub fn handler(ctx: Context<ClaimWinnings>) -> Result<()> {
                 }
             }
         }
+        winner_count
+    };
 
-        require!(winner_count > 0, ErrorCode::NoWinnings);
+    // This player's payout group: the winning side, the survivors, or the
+    // closest-price tier of their target
+    let prediction = player_entry.prediction;
+    let player_index = player_entry.player_index;
+    let in_group = |entry: &PlayerEntry| -> bool {
+        if match_account.is_elimination_mode() {
+            match_account.is_survivor(entry.player_index)
+        } else if match_account.is_closest_price_mode() {
+            match (entry.prediction, prediction, match_account.end_price) {
+                (
+                    Some(PredictionSide::TargetPrice(target)),
+                    Some(PredictionSide::TargetPrice(own_target)),
+                    Some(end_price),
+                ) => target.abs_diff(end_price) == own_target.abs_diff(end_price),
+                _ => false,
+            }
+        } else {
+            entry.prediction == match_account.winning_side
+        }
+    };
 
-        if is_winner {
-            prize_pool / winner_count
+    // USD-pegged stakes differ per player, so the group's pool is split by
+    // stake. Every entry of the match must be passed to total the group
+    let stake_group = if match_account.usd_entry_fee.is_some() && !is_refund {
+        let mut seen: Vec<Pubkey> = Vec::new();
+        let mut group_size = 0u64;
+        let mut group_stake = 0u64;
+        for account_info in ctx.remaining_accounts.iter() {
+            if account_info.owner != &crate::ID || seen.contains(account_info.key) {
+                continue;
+            }
+            if let Ok(entry) = PlayerEntry::try_deserialize(&mut &account_info.data.borrow()[..]) {
+                if entry.match_account == match_account.key() {
+                    seen.push(account_info.key());
+                    if in_group(&entry) {
+                        group_size += 1;
+                        group_stake = group_stake.checked_add(entry.amount_staked)
+                            .ok_or(ErrorCode::ArithmeticOverflow)?;
+                    }
+                }
+            }
+        }
+        require!(
+            seen.len() == match_account.current_players as usize,
+            ErrorCode::IncompletePlayerEntries
+        );
+        Some((group_size, group_stake))
+    } else {
+        None
+    };
+
+    // This player's share of a prize pool, as if every stake were equal
+    let equal_share_of = |prize_pool: u64| -> Result<u64> {
+        if match_account.is_elimination_mode() {
+            // Survivors of the last round split the prize pool
+            let survivors = match_account.alive_mask.count_ones() as u64;
+
+            Ok(if match_account.is_survivor(player_index) {
+                prize_pool / survivors
+            } else {
+                0
+            })
+        } else if match_account.is_closest_price_mode() {
+            // Tiers were ranked from the targets stored on the match at resolution
+            Ok(match prediction {
+                Some(PredictionSide::TargetPrice(target)) => {
+                    match_account.closest_price_payout(target, prize_pool)
+                },
+                _ => 0,
+            })
         } else {
-            0
+            require!(winner_count > 0, ErrorCode::NoWinnings);
+
+            Ok(if is_winner {
+                prize_pool / winner_count
+            } else {
+                0
+            })
         }
     };
 
+    // ... and weighted by stake on USD-pegged matches
+    let amount_staked = player_entry.amount_staked;
+    let share_of = |prize_pool: u64| -> Result<u64> {
+        let equal_share = equal_share_of(prize_pool)?;
+        Ok(match stake_group {
+            Some((group_size, group_stake)) => {
+                stake_weighted_share(equal_share, group_size, amount_staked, group_stake)
+            },
+            None => equal_share,
+        })
+    };
+
+    // Calculate winnings based on refund vs winner scenario
+    let (per_winner_amount, fee_charged, creator_fee) = if is_refund {
+        // Refund case: everyone gets their entry fee back (no protocol fee),
+        // except that sealed matches pay unrevealed stakes to those who revealed
+        msg!("REFUND: No winners, returning entry fee");
+        let refund = if match_account.sealed {
+            sealed_refund(
+                player_entry.amount_staked,
+                player_entry.prediction.is_some(),
+                match_account.revealed_stake,
+                match_account.total_pot,
+            )
+        } else {
+            player_entry.amount_staked
+        };
+        (refund, 0, 0)
+    } else {
+        let share = share_of(match_account.calculate_prize_pool(player_fee_bps))?;
+        // The fee charged is the part of the fee-free share withheld
+        let fee_charged = share_of(match_account.calculate_prize_pool(0))?
+            .checked_sub(share)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+        // The creator takes their cut of the prize pool out of each payout
+        let creator_fee = (share as u128 * match_account.creator_fee_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+        (share - creator_fee, fee_charged, creator_fee)
+    };
+
+    // A referred player's fee is shared with their referrer (SOL matches
+    // only, as referral balances are held in lamports)
+    let referral_fee = match user_profile.referrer {
+        Some(referrer) if fee_charged > 0 && !match_account.is_token_match() => {
+            let referrer_account = ctx.accounts.referrer_account.as_ref()
+                .ok_or(ErrorCode::InvalidReferrer)?;
+            require_keys_eq!(referrer_account.referrer, referrer, ErrorCode::InvalidReferrer);
+            (fee_charged as u128 * config.referral_share_bps as u128 / BPS_DENOMINATOR as u128) as u64
+        },
+        _ => 0,
+    };
+
+    // Closest-price and elimination winners are whoever gets paid
+    let is_winner = if match_account.is_closest_price_mode() || match_account.is_elimination_mode() {
+        per_winner_amount > 0
+    } else {
+        is_winner
+    };
+
     // Update player entry
     player_entry.claimed = true;
     player_entry.winnings = per_winner_amount;
+    user_profile.settle_entry(player_entry);
 
     // Update user profile stats
+    if match_account.is_elimination_mode() {
+        let rounds = rounds_survived(&match_account.rounds, player_entry.player_index);
+        user_profile.rounds_survived = user_profile.rounds_survived.checked_add(rounds as u64)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+    }
     user_profile.total_matches = user_profile.total_matches.checked_add(1)
         .ok_or(ErrorCode::ArithmeticOverflow)?;
     user_profile.total_wagered = user_profile.total_wagered.checked_add(player_entry.amount_staked)
         .ok_or(ErrorCode::ArithmeticOverflow)?;
+    user_profile.record_volume(player_entry.amount_staked, now);
 
     if is_refund {
         // Refund case: no win/loss, just participation XP
@@ -149,6 +343,7 @@ pub fn handler(ctx: Context<ClaimWinnings>) -> Result<()> {
 
         // Update streak for win
         user_profile.update_streak(true);
+        user_profile.record_jackpot_result(true, match_account.resolved_at.unwrap_or_default());
     } else {
         // Normal loss case
         user_profile.losses = user_profile.losses.checked_add(1)
@@ -162,60 +357,107 @@ pub fn handler(ctx: Context<ClaimWinnings>) -> Result<()> {
 
         // Update streak for loss
         user_profile.update_streak(false);
+        user_profile.record_jackpot_result(false, match_account.resolved_at.unwrap_or_default());
     }
 
+    // A rare win takes a share of the jackpot, set aside for claim_jackpot
+    let trigger = if is_winner && !is_refund {
+        jackpot_trigger(
+            user_profile.jackpot_streak,
+            user_profile.open_entries,
+            player_entry.prediction,
+            match_account.end_price,
+        )
+    } else {
+        None
+    };
+    if let Some(trigger) = trigger.filter(|_| config.jackpot_payout_bps > 0) {
+        let jackpot = ctx.accounts.jackpot.as_mut().ok_or(ErrorCode::InvalidJackpot)?;
+
+        // Earlier awards not yet claimed stay promised to their winners
+        let jackpot_info = jackpot.to_account_info();
+        let balance = jackpot_info.lamports()
+            .saturating_sub(Rent::get()?.minimum_balance(jackpot_info.data_len()));
+        let award = jackpot_award(jackpot.unreserved(balance), config.jackpot_payout_bps);
+
+        // A streak pays once, then counts again from zero
+        if trigger == JackpotTrigger::WinStreak {
+            user_profile.jackpot_streak = 0;
+        }
+
+        if award > 0 {
+            jackpot.record_award(award)?;
+            player_entry.jackpot_award = award;
+
+            emit!(JackpotHit {
+                match_id: match_account.match_id,
+                player: ctx.accounts.player.key(),
+                trigger,
+                amount: award,
+                total_awarded: jackpot.total_awarded,
+            });
+        }
+    }
+
+    let escrow = Escrow::new(
+        match_account,
+        &ctx.accounts.vault,
+        ctx.bumps.vault,
+        ctx.accounts.mint.as_ref(),
+        ctx.accounts.vault_token_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.system_program,
+    )?.with_hook_accounts(ctx.remaining_accounts);
+
     // Transfer winnings or refund if player gets any amount
     if per_winner_amount > 0 {
-        let match_key = match_account.key();
-        let vault_seeds = &[
-            seeds::VAULT,
-            match_key.as_ref(),
-            &[ctx.bumps.vault],
-        ];
-        let signer_seeds = &[&vault_seeds[..]];
-
-        anchor_lang::system_program::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                anchor_lang::system_program::Transfer {
-                    from: ctx.accounts.vault.to_account_info(),
-                    to: ctx.accounts.player.to_account_info(),
-                },
-                signer_seeds,
-            ),
+        escrow.pay(
+            &ctx.accounts.player.to_account_info(),
+            ctx.accounts.player_token_account.as_ref(),
             per_winner_amount,
         )?;
     }
 
-    // Transfer protocol fee to treasury ONLY in non-refund cases
-    // (only once, we can check if vault balance is sufficient)
-    if !is_refund {
-        let protocol_fee = match_account.calculate_protocol_fee(config.protocol_fee_bps);
-        let vault_balance = ctx.accounts.vault.lamports();
-
-        if vault_balance >= protocol_fee {
-            let match_key = match_account.key();
-            let vault_seeds = &[
-                seeds::VAULT,
-                match_key.as_ref(),
-                &[ctx.bumps.vault],
-            ];
-            let signer_seeds = &[&vault_seeds[..]];
-
-            anchor_lang::system_program::transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.system_program.to_account_info(),
-                    anchor_lang::system_program::Transfer {
-                        from: ctx.accounts.vault.to_account_info(),
-                        to: ctx.accounts.treasury.to_account_info(),
-                    },
-                    signer_seeds,
-                ),
-                protocol_fee,
-            )?;
+    // Each winner pays their own (discounted) fee share to the fee vault,
+    // less the referrer's cut
+    let protocol_fee = fee_charged - referral_fee;
+    if protocol_fee > 0 {
+        let fee_vault = ctx.accounts.fee_vault.as_ref().ok_or(ErrorCode::InvalidFeeVault)?;
+        escrow.pay(
+            &fee_vault.to_account_info(),
+            ctx.accounts.fee_vault_token_account.as_ref(),
+            protocol_fee,
+        )?;
+    }
+
+    if creator_fee > 0 {
+        let creator = ctx.accounts.creator.as_ref().ok_or(ErrorCode::InvalidCreatorAccount)?;
+        escrow.pay(creator, ctx.accounts.creator_token_account.as_ref(), creator_fee)?;
+    }
+
+    if referral_fee > 0 {
+        if let Some(referrer_account) = ctx.accounts.referrer_account.as_mut() {
+            escrow.pay(&referrer_account.to_account_info(), None, referral_fee)?;
+            referrer_account.total_accrued = referrer_account.total_accrued.checked_add(referral_fee)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+            emit!(ReferralFeeAccrued {
+                referrer: referrer_account.referrer,
+                player: ctx.accounts.player.key(),
+                match_id: match_account.match_id,
+                amount: referral_fee,
+                total_accrued: referrer_account.total_accrued,
+            });
         }
     }
 
+    // Track what left the vault so insurance covers only what's still owed
+    let paid_out = per_winner_amount
+        .checked_add(fee_charged)
+        .and_then(|amount| amount.checked_add(creator_fee))
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    match_account.record_payout(paid_out)?;
+
     emit!(WinningsClaimed {
         match_id: match_account.match_id,
         player: ctx.accounts.player.key(),
@@ -223,6 +465,9 @@ pub fn handler(ctx: Context<ClaimWinnings>) -> Result<()> {
         is_winner,
         is_refund,
         new_level: user_profile.level,
+        fee_charged,
+        fee_discount_bps: discount_bps,
+        creator_fee,
     });
 
     Ok(())
@@ -254,4 +499,28 @@ pub struct WinningsClaimed {
     pub is_winner: bool,
     pub is_refund: bool,
     pub new_level: u16,
+    /// Protocol fee withheld from this player's winnings
+    pub fee_charged: u64,
+    /// Fee-tier discount applied to that fee
+    pub fee_discount_bps: u16,
+    /// Creator fee withheld from this player's winnings
+    pub creator_fee: u64,
+}
+
+#[event]
+pub struct JackpotHit {
+    pub match_id: u64,
+    pub player: Pubkey,
+    pub trigger: JackpotTrigger,
+    pub amount: u64,
+    pub total_awarded: u64,
+}
+
+#[event]
+pub struct ReferralFeeAccrued {
+    pub referrer: Pubkey,
+    pub player: Pubkey,
+    pub match_id: u64,
+    pub amount: u64,
+    pub total_accrued: u64,
 }
@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use crate::{
+    Market, Match, PlayerEntry, MatchStatus, ErrorCode, seeds, utils::pyth::*,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CommitPredictionParams {
+    /// SHA-256(borsh(prediction) || salt), see utils::sealed
+    pub commitment: [u8; 32],
+}
+
+#[derive(Accounts)]
+pub struct CommitPrediction<'info> {
+    #[account(
+        seeds = [seeds::MARKET, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        seeds = [seeds::MATCH, match_account.match_id.to_le_bytes().as_ref()],
+        bump = match_account.bump,
+        has_one = market,
+        constraint = match_account.sealed @ ErrorCode::NotSealedMatch,
+        constraint = match_account.status == MatchStatus::Open ||
+                     match_account.status == MatchStatus::InProgress @ ErrorCode::InvalidMatchStatus
+    )]
+    pub match_account: Account<'info, Match>,
+
+    #[account(
+        mut,
+        seeds = [
+            seeds::PLAYER_ENTRY,
+            match_account.key().as_ref(),
+            player.key().as_ref()
+        ],
+        bump = player_entry.bump,
+        constraint = player_entry.can_predict() @ ErrorCode::PredictionAlreadyLocked
+    )]
+    pub player_entry: Account<'info, PlayerEntry>,
+
+    /// CHECK: Pyth price update account - validated in handler via validate_price_feed() and get_pyth_price()
+    pub price_update: AccountInfo<'info>,
+
+    pub player: Signer<'info>,
+
+    /// Second market (relative-performance matches only)
+    #[account(
+        seeds = [seeds::MARKET, market_b.market_id.to_le_bytes().as_ref()],
+        bump = market_b.bump
+    )]
+    pub market_b: Option<Account<'info, Market>>,
+
+    /// CHECK: Pyth price account of market_b - key checked in get_second_market_price()
+    pub price_update_b: Option<UncheckedAccount<'info>>,
+}
+
+pub fn handler(ctx: Context<CommitPrediction>, params: CommitPredictionParams) -> Result<()> {
+    let match_account = &mut ctx.accounts.match_account;
+    let player_entry = &mut ctx.accounts.player_entry;
+    let market = &ctx.accounts.market;
+    let clock = Clock::get()?;
+
+    // Check if within prediction window
+    let prediction_deadline = match_account.created_at + match_account.prediction_window;
+    require!(
+        clock.unix_timestamp <= prediction_deadline,
+        ErrorCode::PredictionWindowNotClosed
+    );
+
+    // Get feed ID from market
+    let feed_id_hex = market.pyth_price_feed.to_string();
+
+    // Validate the price update account contains the correct feed
+    validate_price_feed(&ctx.accounts.price_update.to_account_info(), &feed_id_hex)?;
+
+    // First commitment records the start price, as with open predictions
+    if match_account.start_price.is_none() && match_account.status == MatchStatus::Open {
+        let pyth_price = get_pyth_price(
+            &ctx.accounts.price_update,
+            &feed_id_hex,
+            &clock,
+        )?;
+
+        require!(
+            pyth_price.is_confidence_acceptable(),
+            ErrorCode::ConfidenceIntervalTooWide
+        );
+
+        msg!("Start price set: {}", pyth_price.normalized_price);
+
+        // Relative matches capture the second market at the same moment
+        if let Some(market_b) = match_account.market_b {
+            let price_update_b = ctx.accounts.price_update_b.as_ref().map(|a| a.to_account_info());
+            let start_price_b = get_second_market_price(
+                &market_b,
+                ctx.accounts.market_b.as_ref(),
+                price_update_b.as_ref(),
+                &clock,
+            )?;
+            msg!("Second market start price set: {}", start_price_b);
+            match_account.start_price_b = Some(start_price_b);
+        }
+
+        match_account.start_price = Some(pyth_price.normalized_price);
+        match_account.status = MatchStatus::InProgress;
+        match_account.started_at = Some(clock.unix_timestamp);
+    }
+
+    // Lock time counts from the commitment, not the reveal
+    player_entry.commitment = Some(params.commitment);
+    player_entry.prediction_locked_at = Some(clock.unix_timestamp);
+
+    emit!(PredictionCommitted {
+        match_id: match_account.match_id,
+        player: ctx.accounts.player.key(),
+        start_price: match_account.start_price,
+        locked_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct PredictionCommitted {
+    pub match_id: u64,
+    pub player: Pubkey,
+    pub start_price: Option<u64>,
+    pub locked_at: i64,
+}
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use crate::{
+    GameConfig, InsuranceFund, Match, MatchStatus, RoleAssignment, Role, ErrorCode, seeds,
+    constants::MAX_SHORTFALL_REASON_LEN
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CoverShortfallParams {
+    /// Lamports to move into the match vault
+    pub amount: u64,
+    /// What went wrong (incident summary or report link), logged with the payout
+    pub reason: String,
+}
+
+#[derive(Accounts)]
+pub struct CoverShortfall<'info> {
+    #[account(
+        seeds = [seeds::GAME_CONFIG],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [seeds::INSURANCE_FUND],
+        bump = insurance_fund.bump
+    )]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+
+    #[account(
+        seeds = [seeds::MATCH, match_account.match_id.to_le_bytes().as_ref()],
+        bump = match_account.bump,
+        constraint = matches!(
+            match_account.status,
+            MatchStatus::Completed | MatchStatus::Cancelled
+        ) @ ErrorCode::InvalidMatchStatus
+    )]
+    pub match_account: Account<'info, Match>,
+
+    /// CHECK: Match vault
+    #[account(
+        mut,
+        seeds = [seeds::VAULT, match_account.key().as_ref()],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    /// InsuranceManager role of the signer (not needed for the config authority)
+    #[account(
+        seeds = [seeds::ROLE, Role::InsuranceManager.seed(), signer.key().as_ref()],
+        bump = role_assignment.bump
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    pub signer: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CoverShortfall>, params: CoverShortfallParams) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    require!(
+        ctx.accounts.config.has_role(
+            &signer,
+            Role::InsuranceManager,
+            ctx.accounts.role_assignment.as_deref()
+        ),
+        ErrorCode::Unauthorized
+    );
+
+    let match_account = &ctx.accounts.match_account;
+    // The fund holds lamports, so only SOL vaults can be topped up
+    require!(!match_account.is_token_match(), ErrorCode::InvalidShortfall);
+    require!(
+        !params.reason.is_empty() && params.reason.len() <= MAX_SHORTFALL_REASON_LEN,
+        ErrorCode::InvalidShortfallReason
+    );
+    require!(params.amount > 0, ErrorCode::InvalidInsuranceAmount);
+
+    // A cover can never leave the vault holding more than unclaimed entries
+    // are still owed (unknown for matches created before payouts were tracked)
+    let owed = match_account.still_owed().ok_or(ErrorCode::InvalidShortfall)?;
+    let vault = &ctx.accounts.vault;
+    let shortfall = owed.saturating_sub(vault.lamports());
+    require!(shortfall > 0, ErrorCode::NoShortfall);
+    require!(params.amount <= shortfall, ErrorCode::InvalidShortfall);
+
+    // Only lamports above the fund's rent-exempt minimum can be paid out
+    let insurance_fund = &mut ctx.accounts.insurance_fund;
+    let fund_info = insurance_fund.to_account_info();
+    let available = fund_info.lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(fund_info.data_len()));
+    require!(params.amount <= available, ErrorCode::InsufficientInsuranceFunds);
+
+    **fund_info.try_borrow_mut_lamports()? -= params.amount;
+    **vault.try_borrow_mut_lamports()? += params.amount;
+
+    insurance_fund.total_covered = insurance_fund.total_covered.checked_add(params.amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    insurance_fund.shortfalls_covered = insurance_fund.shortfalls_covered.checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(ShortfallCovered {
+        match_id: match_account.match_id,
+        vault: vault.key(),
+        amount: params.amount,
+        shortfall,
+        reason: params.reason,
+        covered_by: signer,
+        total_covered: insurance_fund.total_covered,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ShortfallCovered {
+    pub match_id: u64,
+    pub vault: Pubkey,
+    pub amount: u64,
+    /// Vault balance below what unclaimed entries are still owed, before the cover
+    pub shortfall: u64,
+    pub reason: String,
+    pub covered_by: Pubkey,
+    pub total_covered: u64,
+}
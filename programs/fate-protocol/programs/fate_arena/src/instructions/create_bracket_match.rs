@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use crate::{
+    GameConfig, Market, Match, PlayerEntry, Tournament,
+    MatchType, TournamentStatus, ErrorCode, seeds
+};
+
+/// Permissionless crank: creates the next pairing of the current round
+/// The payer covers rent for the match and both player entries
+#[derive(Accounts)]
+pub struct CreateBracketMatch<'info> {
+    #[account(
+        mut,
+        seeds = [seeds::GAME_CONFIG],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::GamePaused
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [seeds::TOURNAMENT, tournament.tournament_id.to_le_bytes().as_ref()],
+        bump = tournament.bump,
+        has_one = market,
+        constraint = tournament.status == TournamentStatus::InProgress @ ErrorCode::InvalidTournamentStatus,
+        constraint = tournament.round_match_ids.len() < tournament.pairings_in_round() @ ErrorCode::RoundAlreadyScheduled
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        mut,
+        seeds = [seeds::MARKET, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Match::LEN,
+        seeds = [seeds::MATCH, config.total_matches.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub match_account: Account<'info, Match>,
+
+    /// CHECK: Must be the first player of the next pairing (checked in handler)
+    pub player_a: UncheckedAccount<'info>,
+
+    /// CHECK: Must be the second player of the next pairing (checked in handler)
+    pub player_b: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = PlayerEntry::LEN,
+        seeds = [
+            seeds::PLAYER_ENTRY,
+            match_account.key().as_ref(),
+            player_a.key().as_ref()
+        ],
+        bump
+    )]
+    pub entry_a: Account<'info, PlayerEntry>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = PlayerEntry::LEN,
+        seeds = [
+            seeds::PLAYER_ENTRY,
+            match_account.key().as_ref(),
+            player_b.key().as_ref()
+        ],
+        bump
+    )]
+    pub entry_b: Account<'info, PlayerEntry>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateBracketMatch>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let tournament = &mut ctx.accounts.tournament;
+    let match_account = &mut ctx.accounts.match_account;
+    let clock = Clock::get()?;
+
+    // Resolve the next pairing from the surviving bracket
+    let pairing = tournament.round_match_ids.len();
+    let alive = tournament.alive_in_bracket();
+    let player_a = tournament.players[alive[2 * pairing] as usize];
+    let player_b = tournament.players[alive[2 * pairing + 1] as usize];
+
+    require_keys_eq!(ctx.accounts.player_a.key(), player_a, ErrorCode::InvalidBracketAccount);
+    require_keys_eq!(ctx.accounts.player_b.key(), player_b, ErrorCode::InvalidBracketAccount);
+
+    let match_id = config.total_matches;
+
+    // Stakes stay in the tournament vault, so the child match carries no pot
+    match_account.set_inner(Match {
+        max_players: 2,
+        current_players: 2,
+        prediction_window: tournament.prediction_window,
+        resolution_time: clock.unix_timestamp + tournament.prediction_window + tournament.match_duration,
+        alive_mask: 0b11,
+        ..Match::new(
+            match_id,
+            tournament.market,
+            tournament.key(),
+            MatchType::Tournament,
+            ctx.bumps.match_account,
+            clock.unix_timestamp,
+        )
+    });
+
+    for (index, entry, player, bump) in [
+        (0, &mut ctx.accounts.entry_a, player_a, ctx.bumps.entry_a),
+        (1, &mut ctx.accounts.entry_b, player_b, ctx.bumps.entry_b),
+    ] {
+        entry.match_account = match_account.key();
+        entry.player = player;
+        entry.prediction = None;
+        entry.amount_staked = 0;
+        entry.prediction_locked_at = None;
+        entry.claimed = false;
+        entry.winnings = 0;
+        entry.bump = bump;
+        entry.version = PlayerEntry::VERSION;
+        entry.commitment = None;
+        entry.player_index = index;
+        entry.jackpot_award = 0;
+    }
+
+    tournament.round_match_ids.push(match_id);
+
+    // Update counters
+    config.total_matches = config.total_matches.checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    ctx.accounts.market.total_matches = ctx.accounts.market.total_matches.checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(BracketMatchCreated {
+        tournament_id: tournament.tournament_id,
+        round: tournament.current_round,
+        pairing: pairing as u8,
+        match_id,
+        player_a,
+        player_b,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct BracketMatchCreated {
+    pub tournament_id: u64,
+    pub round: u8,
+    pub pairing: u8,
+    pub match_id: u64,
+    pub player_a: Pubkey,
+    pub player_b: Pubkey,
+}
@@ -1,10 +1,16 @@
 use anchor_lang::prelude::*;
-use crate::{GameConfig, Market, ErrorCode, seeds};
+use anchor_spl::token_interface::Mint;
+use crate::{
+    GameConfig, Market, MarketLimits, RoleAssignment, Role, ErrorCode, seeds,
+    utils::buckets::validate_bucket_bounds, utils::escrow::validate_mint_extensions
+};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct CreateMarketParams {
     pub name: String,
     pub description: String,
+    /// Price-change bucket boundaries in bps for range predictions (None = not offered)
+    pub bucket_bounds_bps: Option<Vec<i32>>,
 }
 
 #[derive(Accounts)]
@@ -12,8 +18,7 @@ pub struct CreateMarket<'info> {
     #[account(
         mut,
         seeds = [seeds::GAME_CONFIG],
-        bump = config.bump,
-        has_one = authority @ ErrorCode::Unauthorized
+        bump = config.bump
     )]
     pub config: Account<'info, GameConfig>,
 
@@ -30,16 +35,36 @@ pub struct CreateMarket<'info> {
     /// CHECK: Validated in handler
     pub pyth_price_feed: AccountInfo<'info>,
 
+    /// MarketManager role of the signer (not needed for the config authority)
+    #[account(
+        seeds = [seeds::ROLE, Role::MarketManager.seed(), authority.key().as_ref()],
+        bump = role_assignment.bump
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    /// Config authority or market manager
     #[account(mut)]
     pub authority: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// SPL mint every match on this market stakes in (None = SOL)
+    pub accepted_mint: Option<InterfaceAccount<'info, Mint>>,
 }
 
 pub fn handler(ctx: Context<CreateMarket>, params: CreateMarketParams) -> Result<()> {
     let config = &mut ctx.accounts.config;
     let market = &mut ctx.accounts.market;
 
+    require!(
+        config.has_role(
+            &ctx.accounts.authority.key(),
+            Role::MarketManager,
+            ctx.accounts.role_assignment.as_deref()
+        ),
+        ErrorCode::Unauthorized
+    );
+
     // Validate name and description lengths
     require!(
         params.name.len() <= 32,
@@ -50,6 +75,13 @@ pub fn handler(ctx: Context<CreateMarket>, params: CreateMarketParams) -> Result
         ErrorCode::InvalidMarketDescription
     );
 
+    // Validate range prediction buckets
+    let bucket_bounds_bps = params.bucket_bounds_bps.unwrap_or_default();
+    require!(
+        bucket_bounds_bps.is_empty() || validate_bucket_bounds(&bucket_bounds_bps),
+        ErrorCode::InvalidPriceBuckets
+    );
+
     // Validate Pyth account using utility function
     use crate::utils::pyth::validate_price_feed;
     validate_price_feed(&ctx.accounts.pyth_price_feed, "")?;
@@ -75,6 +107,15 @@ pub fn handler(ctx: Context<CreateMarket>, params: CreateMarketParams) -> Result
     market.total_matches = 0;
     market.created_at = Clock::get()?.unix_timestamp;
     market.bump = ctx.bumps.market;
+    market.version = Market::VERSION;
+    market.bucket_bounds_bps = bucket_bounds_bps;
+    market.templates = Vec::new();
+    market.limits = MarketLimits::default();
+    // SPL Token or Token-2022 mint without extensions that could lock the vault
+    if let Some(mint) = &ctx.accounts.accepted_mint {
+        validate_mint_extensions(&mint.to_account_info().try_borrow_data()?)?;
+    }
+    market.accepted_mint = ctx.accounts.accepted_mint.as_ref().map(|mint| mint.key());
 
     // Update config
     config.total_matches = config.total_matches.checked_add(1)
@@ -84,6 +125,7 @@ pub fn handler(ctx: Context<CreateMarket>, params: CreateMarketParams) -> Result
         market_id,
         name: params.name,
         pyth_price_feed: market.pyth_price_feed,
+        accepted_mint: market.accepted_mint,
     });
 
     Ok(())
@@ -94,4 +136,6 @@ pub struct MarketCreated {
     pub market_id: u64,
     pub name: String,
     pub pyth_price_feed: Pubkey,
+    /// Stake mint for the market's matches (None = SOL)
+    pub accepted_mint: Option<Pubkey>,
 }
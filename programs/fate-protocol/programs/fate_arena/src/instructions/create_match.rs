@@ -1,17 +1,39 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::{
     GameConfig, Market, Match, PlayerEntry, UserProfile,
-    MatchType, MatchStatus, ErrorCode, seeds, constants::*
+    MatchType, MatchAccess, ErrorCode, seeds, constants::*,
+    UsdEntryFee, utils::closest_price::validate_payout_tiers,
+    utils::escrow::{validate_mint_extensions, Escrow}, utils::pyth::get_usd_entry_fee_lamports
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct CreateMatchParams {
     pub match_type: MatchType,
+    /// Entry fee in lamports or mint base units (ignored for USD-pegged matches)
     pub entry_fee: u64,
     pub max_players: u8,
     pub prediction_window: i64,
     pub match_duration: i64,
+    /// Optional invite allowlist or passphrase commitment (None = public)
+    pub access: Option<MatchAccess>,
+    /// Predict the market's price-change buckets instead of Higher/Lower (BattleRoyale only)
+    pub range_buckets: bool,
+    /// Closest-price mode: payout share per tier in bps (None = side predictions)
+    pub payout_tiers_bps: Option<Vec<u16>>,
+    /// Commit-reveal predictions: sides stay hidden until the window closes
+    pub sealed: bool,
+    /// Volatility mode: predict a move beyond or within this many bps
+    pub volatility_threshold_bps: Option<u16>,
+    /// Elimination BattleRoyale: number of rounds (0 = single round)
+    pub elimination_rounds: u8,
+    /// Peg the entry fee to this many US cents, converted at each join (SOL only)
+    pub entry_fee_usd_cents: Option<u64>,
+    /// Slippage bound on the creator's converted stake (required with a USD fee)
+    pub max_entry_lamports: Option<u64>,
+    /// Creator's cut of the prize pool in bps (capped by the config)
+    pub creator_fee_bps: u16,
 }
 
 #[derive(Accounts)]
@@ -73,9 +95,43 @@ pub struct CreateMatch<'info> {
     pub creator: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Second market for a relative-performance match (players predict
+    /// which market's percent change is larger)
+    #[account(
+        seeds = [seeds::MARKET, market_b.market_id.to_le_bytes().as_ref()],
+        bump = market_b.bump,
+        constraint = market_b.active @ ErrorCode::MarketNotActive
+    )]
+    pub market_b: Option<Account<'info, Market>>,
+
+    /// Mint to stake in (required on markets with an accepted mint, None = SOL)
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Token vault: the match PDA's associated token account for `mint`
+    #[account(
+        init,
+        payer = creator,
+        associated_token::mint = mint,
+        associated_token::authority = match_account,
+        associated_token::token_program = token_program
+    )]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Creator's token account the stake is paid from
+    #[account(mut)]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    /// CHECK: SOL/USD price account (USD-pegged matches only), checked
+    /// against the feed in the config
+    pub sol_usd_price_update: Option<UncheckedAccount<'info>>,
 }
 
-pub fn handler(ctx: Context<CreateMatch>, params: CreateMatchParams) -> Result<()> {
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, CreateMatch<'info>>, params: CreateMatchParams) -> Result<()> {
     let config = &mut ctx.accounts.config;
     let market = &mut ctx.accounts.market;
     let match_account = &mut ctx.accounts.match_account;
@@ -83,27 +139,81 @@ pub fn handler(ctx: Context<CreateMatch>, params: CreateMatchParams) -> Result<(
     let user_profile = &mut ctx.accounts.user_profile;
     let clock = Clock::get()?;
 
-    // Validate parameters
-    require!(
-        params.entry_fee >= MIN_ENTRY_FEE && params.entry_fee <= MAX_ENTRY_FEE,
-        ErrorCode::InvalidEntryFee
-    );
+    // Token matches stake in the market's accepted mint, or one the creator names
+    let mint = ctx.accounts.mint.as_ref().map(|m| m.key());
+    if market.accepted_mint.is_some() {
+        require!(mint == market.accepted_mint, ErrorCode::InvalidMint);
+    } else if let Some(mint_account) = &ctx.accounts.mint {
+        validate_mint_extensions(&mint_account.to_account_info().try_borrow_data()?)?;
+    }
+
+    // USD-pegged matches convert the fee to lamports at the SOL/USD price
+    let usd_entry_fee = match params.entry_fee_usd_cents {
+        Some(cents) => {
+            require!(mint.is_none(), ErrorCode::InvalidMint);
+            require!(
+                config.sol_usd_feed != Pubkey::default(),
+                ErrorCode::UsdPricingUnavailable
+            );
+            require!(
+                (MIN_ENTRY_FEE_USD_CENTS..=MAX_ENTRY_FEE_USD_CENTS).contains(&cents),
+                ErrorCode::InvalidEntryFee
+            );
+            Some(UsdEntryFee { cents, price_feed: config.sol_usd_feed })
+        },
+        None => None,
+    };
+    let entry_fee = match &usd_entry_fee {
+        Some(usd_entry_fee) => get_usd_entry_fee_lamports(
+            usd_entry_fee,
+            ctx.accounts.sol_usd_price_update.as_ref().map(|a| a.as_ref()),
+            params.max_entry_lamports,
+            &clock,
+        )?,
+        None => params.entry_fee,
+    };
+
+    // Validate parameters against the market's limits (USD fees are bounded
+    // in cents above; a mint the creator names has no bounds in its units)
+    let limits = &market.limits;
+    if mint.is_some() && market.accepted_mint.is_none() {
+        require!(params.entry_fee > 0, ErrorCode::InvalidEntryFee);
+    } else if usd_entry_fee.is_none() {
+        require!(
+            limits.entry_fee_range(mint.is_some()).contains(&params.entry_fee),
+            ErrorCode::InvalidEntryFee
+        );
+    }
     require!(
         params.max_players >= 2 && params.max_players <= MAX_PLAYERS,
         ErrorCode::InvalidMaxPlayers
     );
     require!(
-        params.prediction_window >= MIN_PREDICTION_WINDOW &&
-        params.prediction_window <= MAX_PREDICTION_WINDOW,
+        limits.prediction_window_range().contains(&params.prediction_window),
         ErrorCode::InvalidPredictionWindow
     );
     require!(
-        params.match_duration >= MIN_MATCH_DURATION &&
-        params.match_duration <= MAX_MATCH_DURATION,
+        limits.match_duration_range().contains(&params.match_duration),
         ErrorCode::InvalidPredictionWindow
     );
+    require!(
+        limits.allows(params.match_type),
+        ErrorCode::MatchTypeNotAllowed
+    );
+    require!(
+        params.creator_fee_bps <= config.max_creator_fee_bps,
+        ErrorCode::InvalidCreatorFee
+    );
 
-    // Validate match type
+    // Validate match type (tournament and series rounds are spawned by their parent)
+    require!(
+        params.match_type != MatchType::Tournament,
+        ErrorCode::TournamentMatch
+    );
+    require!(
+        params.match_type != MatchType::Series,
+        ErrorCode::SeriesMatch
+    );
     if params.match_type == MatchType::BattleRoyale {
         require!(
             params.max_players >= MIN_BATTLE_ROYALE_PLAYERS,
@@ -111,49 +221,139 @@ pub fn handler(ctx: Context<CreateMatch>, params: CreateMatchParams) -> Result<(
         );
     }
 
+    // Range predictions need a BattleRoyale on a market that defines buckets
+    if params.range_buckets {
+        require!(
+            params.match_type == MatchType::BattleRoyale &&
+            !market.bucket_bounds_bps.is_empty(),
+            ErrorCode::InvalidPriceBuckets
+        );
+    }
+
+    // Closest-price matches rank exact targets, so they can't also use buckets
+    if let Some(tiers) = &params.payout_tiers_bps {
+        require!(
+            !params.range_buckets && validate_payout_tiers(tiers),
+            ErrorCode::InvalidPayoutTiers
+        );
+    }
+
+    // Relative-performance matches compare two distinct markets with side predictions
+    let market_b = ctx.accounts.market_b.as_ref().map(|m| m.key());
+    if let Some(market_b) = market_b {
+        require!(
+            market_b != market.key() &&
+            !params.range_buckets &&
+            params.payout_tiers_bps.is_none(),
+            ErrorCode::InvalidSecondMarket
+        );
+    }
+
+    // Volatility matches use their own sides, so no other prediction mode
+    if let Some(threshold) = params.volatility_threshold_bps {
+        require!(
+            (MIN_VOLATILITY_THRESHOLD_BPS..=MAX_VOLATILITY_THRESHOLD_BPS).contains(&threshold) &&
+            !params.range_buckets &&
+            params.payout_tiers_bps.is_none() &&
+            market_b.is_none(),
+            ErrorCode::InvalidVolatilityThreshold
+        );
+    }
+
+    // Elimination rounds are plain Higher/Lower calls repeated each round
+    if params.elimination_rounds > 0 {
+        require!(
+            params.match_type == MatchType::BattleRoyale &&
+            (MIN_ELIMINATION_ROUNDS..=MAX_ELIMINATION_ROUNDS).contains(&params.elimination_rounds) &&
+            !params.range_buckets &&
+            params.payout_tiers_bps.is_none() &&
+            params.volatility_threshold_bps.is_none() &&
+            market_b.is_none() &&
+            !params.sealed,
+            ErrorCode::InvalidEliminationRounds
+        );
+    }
+
+    // Validate access policy
+    if let Some(MatchAccess::Allowlist { players }) = &params.access {
+        require!(
+            !players.is_empty() &&
+            players.len() <= MAX_ALLOWLIST_PLAYERS &&
+            players.len() < params.max_players as usize,
+            ErrorCode::InvalidAccessPolicy
+        );
+    }
+
     let match_id = config.total_matches;
 
     // Initialize match
-    match_account.match_id = match_id;
-    match_account.market = market.key();
-    match_account.creator = ctx.accounts.creator.key();
-    match_account.match_type = params.match_type;
-    match_account.entry_fee = params.entry_fee;
-    match_account.max_players = params.max_players;
-    match_account.current_players = 1; // Creator joins
-    match_account.status = MatchStatus::Open;
-    match_account.start_price = None;
-    match_account.end_price = None;
-    match_account.prediction_window = params.prediction_window;
-    match_account.resolution_time = clock.unix_timestamp + params.prediction_window + params.match_duration;
-    match_account.winning_side = None;
-    match_account.total_pot = params.entry_fee;
-    match_account.created_at = clock.unix_timestamp;
-    match_account.started_at = None;
-    match_account.resolved_at = None;
-    match_account.bump = ctx.bumps.match_account;
+    let (bucket_bounds_bps, bucket_counts) = if params.range_buckets {
+        (market.bucket_bounds_bps.clone(), vec![0; market.bucket_bounds_bps.len() + 1])
+    } else {
+        (Vec::new(), Vec::new())
+    };
+    match_account.set_inner(Match {
+        entry_fee,
+        max_players: params.max_players,
+        current_players: 1, // Creator joins
+        prediction_window: params.prediction_window,
+        resolution_time: clock.unix_timestamp + params.prediction_window + params.match_duration,
+        total_pot: entry_fee,
+        access: params.access,
+        bucket_bounds_bps,
+        bucket_counts,
+        payout_tiers_bps: params.payout_tiers_bps.unwrap_or_default(),
+        sealed: params.sealed,
+        market_b,
+        volatility_threshold_bps: params.volatility_threshold_bps,
+        elimination_rounds: params.elimination_rounds,
+        alive_mask: 1, // Creator is player 0
+        mint,
+        usd_entry_fee,
+        protocol_fee_bps: market.limits.protocol_fee_bps,
+        creator_fee_bps: params.creator_fee_bps,
+        ..Match::new(
+            match_id,
+            market.key(),
+            ctx.accounts.creator.key(),
+            params.match_type,
+            ctx.bumps.match_account,
+            clock.unix_timestamp,
+        )
+    });
 
     // Initialize player entry for creator
     player_entry.match_account = match_account.key();
     player_entry.player = ctx.accounts.creator.key();
     player_entry.prediction = None;
-    player_entry.amount_staked = params.entry_fee;
+    player_entry.amount_staked = entry_fee;
     player_entry.prediction_locked_at = None;
     player_entry.claimed = false;
     player_entry.winnings = 0;
     player_entry.bump = ctx.bumps.player_entry;
+    player_entry.version = PlayerEntry::VERSION;
+    player_entry.commitment = None;
+    player_entry.player_index = 0;
+    player_entry.jackpot_award = 0;
+    user_profile.open_entry(player_entry)?;
 
-    // Transfer entry fee to vault
-    system_program::transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.creator.to_account_info(),
-                to: ctx.accounts.vault.to_account_info(),
-            },
-        ),
-        params.entry_fee,
+    // Transfer entry fee to vault; settlement uses what the vault received
+    // (less than the entry fee for Token-2022 mints with a transfer fee)
+    let received = Escrow::new(
+        match_account,
+        &ctx.accounts.vault,
+        ctx.bumps.vault,
+        ctx.accounts.mint.as_ref(),
+        ctx.accounts.vault_token_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.system_program,
+    )?.with_hook_accounts(ctx.remaining_accounts).deposit(
+        &ctx.accounts.creator.to_account_info(),
+        ctx.accounts.creator_token_account.as_ref(),
+        entry_fee,
     )?;
+    match_account.total_pot = received;
+    player_entry.amount_staked = received;
 
     // Update counters
     config.total_matches = config.total_matches.checked_add(1)
@@ -166,8 +366,18 @@ pub fn handler(ctx: Context<CreateMatch>, params: CreateMatchParams) -> Result<(
         market: market.key(),
         creator: ctx.accounts.creator.key(),
         match_type: params.match_type,
-        entry_fee: params.entry_fee,
+        entry_fee,
         max_players: params.max_players,
+        is_private: match_account.is_private(),
+        bucket_bounds_bps: match_account.bucket_bounds_bps.clone(),
+        payout_tiers_bps: match_account.payout_tiers_bps.clone(),
+        sealed: params.sealed,
+        market_b,
+        volatility_threshold_bps: params.volatility_threshold_bps,
+        elimination_rounds: params.elimination_rounds,
+        mint,
+        entry_fee_usd_cents: params.entry_fee_usd_cents,
+        creator_fee_bps: params.creator_fee_bps,
     });
 
     Ok(())
@@ -181,4 +391,25 @@ pub struct MatchCreated {
     pub match_type: MatchType,
     pub entry_fee: u64,
     pub max_players: u8,
+    /// Private matches should be hidden from public lobbies
+    pub is_private: bool,
+    /// Bucket boundaries for range predictions (empty = Higher/Lower)
+    pub bucket_bounds_bps: Vec<i32>,
+    /// Payout tiers for closest-price matches (empty = side predictions)
+    pub payout_tiers_bps: Vec<u16>,
+    pub sealed: bool,
+    /// Second market of a relative-performance match
+    pub market_b: Option<Pubkey>,
+    /// Move threshold of a volatility match
+    pub volatility_threshold_bps: Option<u16>,
+    /// Rounds of an elimination BattleRoyale (0 = single round)
+    pub elimination_rounds: u8,
+    /// Stake mint (None = SOL)
+    pub mint: Option<Pubkey>,
+    /// USD peg of the entry fee (`entry_fee` is the creator's converted stake)
+    /// Each joiner stakes the lamports the peg converts to at join time, and
+    /// winners are paid in proportion to their stake
+    pub entry_fee_usd_cents: Option<u64>,
+    /// Creator's cut of the prize pool in bps, taken from winners' payouts
+    pub creator_fee_bps: u16,
 }
@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::{
+    GameConfig, Market, MatchType, Series, SeriesStatus, ErrorCode, seeds, constants::*
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateSeriesParams {
+    /// Stake per player, paid once for the whole series
+    pub entry_fee: u64,
+    /// Number of rounds: 3, 5 or 7
+    pub best_of: u8,
+    /// Prediction window for each round
+    pub prediction_window: i64,
+    /// Duration of each round after the prediction window
+    pub match_duration: i64,
+    /// Restrict the series to one opponent (None = open challenge)
+    pub invited: Option<Pubkey>,
+}
+
+#[derive(Accounts)]
+pub struct CreateSeries<'info> {
+    #[account(
+        mut,
+        seeds = [seeds::GAME_CONFIG],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::GamePaused
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        seeds = [seeds::MARKET, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.active @ ErrorCode::MarketNotActive,
+        // Stakes are held in lamports
+        constraint = market.accepted_mint.is_none() @ ErrorCode::InvalidMint
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = Series::LEN,
+        seeds = [seeds::SERIES, config.total_series.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub series: Account<'info, Series>,
+
+    /// CHECK: Series escrow vault
+    #[account(
+        mut,
+        seeds = [seeds::VAULT, series.key().as_ref()],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateSeries>, params: CreateSeriesParams) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let series = &mut ctx.accounts.series;
+    let clock = Clock::get()?;
+
+    // Validate parameters
+    let limits = &ctx.accounts.market.limits;
+    require!(
+        limits.allows(MatchType::Series),
+        ErrorCode::MatchTypeNotAllowed
+    );
+    require!(
+        limits.entry_fee_range(false).contains(&params.entry_fee),
+        ErrorCode::InvalidEntryFee
+    );
+    require!(
+        params.best_of >= 3 &&
+        params.best_of <= MAX_SERIES_LENGTH &&
+        params.best_of % 2 == 1,
+        ErrorCode::InvalidSeriesLength
+    );
+    require!(
+        limits.prediction_window_range().contains(&params.prediction_window),
+        ErrorCode::InvalidPredictionWindow
+    );
+    require!(
+        limits.match_duration_range().contains(&params.match_duration),
+        ErrorCode::InvalidPredictionWindow
+    );
+    require!(
+        params.invited != Some(ctx.accounts.creator.key()),
+        ErrorCode::InvalidAccessPolicy
+    );
+
+    // Stake once for the whole series
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.creator.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+            },
+        ),
+        params.entry_fee,
+    )?;
+
+    let series_id = config.total_series;
+
+    // Initialize series
+    series.series_id = series_id;
+    series.market = ctx.accounts.market.key();
+    series.player_a = ctx.accounts.creator.key();
+    series.player_b = None;
+    series.invited = params.invited;
+    series.entry_fee = params.entry_fee;
+    series.best_of = params.best_of;
+    series.wins_a = 0;
+    series.wins_b = 0;
+    series.matches_played = 0;
+    series.status = SeriesStatus::Open;
+    series.prediction_window = params.prediction_window;
+    series.match_duration = params.match_duration;
+    series.current_match_id = None;
+    series.total_pot = params.entry_fee;
+    series.prize_pool = 0;
+    series.winner = None;
+    series.claimed_a = false;
+    series.claimed_b = false;
+    series.created_at = clock.unix_timestamp;
+    series.completed_at = None;
+    series.bump = ctx.bumps.series;
+    series.version = Series::VERSION;
+    series.protocol_fee_bps = limits.protocol_fee_bps;
+
+    config.total_series = config.total_series.checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(SeriesCreated {
+        series_id,
+        market: series.market,
+        creator: series.player_a,
+        invited: params.invited,
+        entry_fee: params.entry_fee,
+        best_of: params.best_of,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct SeriesCreated {
+    pub series_id: u64,
+    pub market: Pubkey,
+    pub creator: Pubkey,
+    pub invited: Option<Pubkey>,
+    pub entry_fee: u64,
+    pub best_of: u8,
+}
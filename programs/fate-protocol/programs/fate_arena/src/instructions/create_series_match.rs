@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+use crate::{
+    GameConfig, Market, Match, PlayerEntry, Series,
+    MatchType, SeriesStatus, ErrorCode, seeds
+};
+
+/// Permissionless crank: creates the next round of a series
+/// The payer covers rent for the match and both player entries
+#[derive(Accounts)]
+pub struct CreateSeriesMatch<'info> {
+    #[account(
+        mut,
+        seeds = [seeds::GAME_CONFIG],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::GamePaused
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [seeds::SERIES, series.series_id.to_le_bytes().as_ref()],
+        bump = series.bump,
+        has_one = market,
+        constraint = series.status == SeriesStatus::InProgress @ ErrorCode::InvalidSeriesStatus,
+        constraint = series.current_match_id.is_none() @ ErrorCode::SeriesRoundPending
+    )]
+    pub series: Account<'info, Series>,
+
+    #[account(
+        mut,
+        seeds = [seeds::MARKET, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Match::LEN,
+        seeds = [seeds::MATCH, config.total_matches.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub match_account: Account<'info, Match>,
+
+    /// CHECK: Must be the series creator
+    #[account(constraint = player_a.key() == series.player_a @ ErrorCode::NotSeriesPlayer)]
+    pub player_a: UncheckedAccount<'info>,
+
+    /// CHECK: Must be the series opponent
+    #[account(constraint = Some(player_b.key()) == series.player_b @ ErrorCode::NotSeriesPlayer)]
+    pub player_b: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = PlayerEntry::LEN,
+        seeds = [
+            seeds::PLAYER_ENTRY,
+            match_account.key().as_ref(),
+            player_a.key().as_ref()
+        ],
+        bump
+    )]
+    pub entry_a: Account<'info, PlayerEntry>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = PlayerEntry::LEN,
+        seeds = [
+            seeds::PLAYER_ENTRY,
+            match_account.key().as_ref(),
+            player_b.key().as_ref()
+        ],
+        bump
+    )]
+    pub entry_b: Account<'info, PlayerEntry>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateSeriesMatch>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let series = &mut ctx.accounts.series;
+    let match_account = &mut ctx.accounts.match_account;
+    let clock = Clock::get()?;
+
+    let player_a = ctx.accounts.player_a.key();
+    let player_b = ctx.accounts.player_b.key();
+    let match_id = config.total_matches;
+
+    // Stakes stay in the series vault, so the child match carries no pot
+    match_account.set_inner(Match {
+        max_players: 2,
+        current_players: 2,
+        prediction_window: series.prediction_window,
+        resolution_time: clock.unix_timestamp + series.prediction_window + series.match_duration,
+        alive_mask: 0b11,
+        ..Match::new(
+            match_id,
+            series.market,
+            series.key(),
+            MatchType::Series,
+            ctx.bumps.match_account,
+            clock.unix_timestamp,
+        )
+    });
+
+    for (index, entry, player, bump) in [
+        (0, &mut ctx.accounts.entry_a, player_a, ctx.bumps.entry_a),
+        (1, &mut ctx.accounts.entry_b, player_b, ctx.bumps.entry_b),
+    ] {
+        entry.match_account = match_account.key();
+        entry.player = player;
+        entry.prediction = None;
+        entry.amount_staked = 0;
+        entry.prediction_locked_at = None;
+        entry.claimed = false;
+        entry.winnings = 0;
+        entry.bump = bump;
+        entry.version = PlayerEntry::VERSION;
+        entry.commitment = None;
+        entry.player_index = index;
+        entry.jackpot_award = 0;
+    }
+
+    series.current_match_id = Some(match_id);
+    series.matches_played = series.matches_played.checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    // Update counters
+    config.total_matches = config.total_matches.checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    ctx.accounts.market.total_matches = ctx.accounts.market.total_matches.checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(SeriesMatchCreated {
+        series_id: series.series_id,
+        round: series.matches_played,
+        match_id,
+        player_a,
+        player_b,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct SeriesMatchCreated {
+    pub series_id: u64,
+    /// 1-based count of round matches played, including replays
+    pub round: u8,
+    pub match_id: u64,
+    pub player_a: Pubkey,
+    pub player_b: Pubkey,
+}
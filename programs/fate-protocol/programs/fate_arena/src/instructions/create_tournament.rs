@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+use crate::{
+    GameConfig, Market, MatchType, Tournament, TournamentStatus, ErrorCode, seeds,
+    constants::*, utils::bracket::total_rounds
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateTournamentParams {
+    pub entry_fee: u64,
+    /// Bracket size: 4, 8 or 16
+    pub max_players: u8,
+    /// Prediction window for each bracket match
+    pub prediction_window: i64,
+    /// Duration of each bracket match after the prediction window
+    pub match_duration: i64,
+    /// Seconds registration stays open
+    pub registration_period: i64,
+    /// Prize split in bps: [champion, runner-up, semifinal losers combined]
+    pub payout_bps: [u16; 3],
+}
+
+#[derive(Accounts)]
+pub struct CreateTournament<'info> {
+    #[account(
+        mut,
+        seeds = [seeds::GAME_CONFIG],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::GamePaused
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        seeds = [seeds::MARKET, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.active @ ErrorCode::MarketNotActive,
+        // Stakes are held in lamports
+        constraint = market.accepted_mint.is_none() @ ErrorCode::InvalidMint
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = Tournament::LEN,
+        seeds = [seeds::TOURNAMENT, config.total_tournaments.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateTournament>, params: CreateTournamentParams) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let tournament = &mut ctx.accounts.tournament;
+    let clock = Clock::get()?;
+
+    // Validate parameters
+    let limits = &ctx.accounts.market.limits;
+    require!(
+        limits.allows(MatchType::Tournament),
+        ErrorCode::MatchTypeNotAllowed
+    );
+    require!(
+        limits.entry_fee_range(false).contains(&params.entry_fee),
+        ErrorCode::InvalidEntryFee
+    );
+    require!(
+        params.max_players >= MIN_TOURNAMENT_PLAYERS &&
+        params.max_players as usize <= MAX_TOURNAMENT_PLAYERS &&
+        params.max_players.is_power_of_two(),
+        ErrorCode::InvalidTournamentSize
+    );
+    require!(
+        limits.prediction_window_range().contains(&params.prediction_window),
+        ErrorCode::InvalidPredictionWindow
+    );
+    require!(
+        limits.match_duration_range().contains(&params.match_duration),
+        ErrorCode::InvalidPredictionWindow
+    );
+    require!(
+        params.registration_period > 0 &&
+        params.registration_period <= MAX_REGISTRATION_PERIOD,
+        ErrorCode::InvalidRegistrationPeriod
+    );
+    let payout_total: u64 = params.payout_bps.iter().map(|&bps| bps as u64).sum();
+    require!(
+        payout_total == BPS_DENOMINATOR as u64,
+        ErrorCode::InvalidPayoutSplit
+    );
+
+    let tournament_id = config.total_tournaments;
+
+    // Initialize tournament
+    tournament.tournament_id = tournament_id;
+    tournament.market = ctx.accounts.market.key();
+    tournament.creator = ctx.accounts.creator.key();
+    tournament.entry_fee = params.entry_fee;
+    tournament.max_players = params.max_players;
+    tournament.status = TournamentStatus::Registration;
+    tournament.prediction_window = params.prediction_window;
+    tournament.match_duration = params.match_duration;
+    tournament.registration_ends_at = clock.unix_timestamp + params.registration_period;
+    tournament.payout_bps = params.payout_bps;
+    tournament.players = Vec::new();
+    tournament.seed_levels = Vec::new();
+    tournament.bracket = Vec::new();
+    tournament.eliminated_in = Vec::new();
+    tournament.current_round = 0;
+    tournament.total_rounds = total_rounds(params.max_players);
+    tournament.round_match_ids = Vec::new();
+    tournament.total_pot = 0;
+    tournament.prize_pool = 0;
+    tournament.claimed_mask = 0;
+    tournament.created_at = clock.unix_timestamp;
+    tournament.started_at = None;
+    tournament.completed_at = None;
+    tournament.bump = ctx.bumps.tournament;
+    tournament.version = Tournament::VERSION;
+    tournament.protocol_fee_bps = limits.protocol_fee_bps;
+
+    config.total_tournaments = config.total_tournaments.checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(TournamentCreated {
+        tournament_id,
+        market: tournament.market,
+        creator: tournament.creator,
+        entry_fee: params.entry_fee,
+        max_players: params.max_players,
+        registration_ends_at: tournament.registration_ends_at,
+        payout_bps: params.payout_bps,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct TournamentCreated {
+    pub tournament_id: u64,
+    pub market: Pubkey,
+    pub creator: Pubkey,
+    pub entry_fee: u64,
+    pub max_players: u8,
+    pub registration_ends_at: i64,
+    pub payout_bps: [u16; 3],
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::{InsuranceFund, ErrorCode, seeds};
+
+#[derive(Accounts)]
+pub struct DepositInsurance<'info> {
+    #[account(
+        mut,
+        seeds = [seeds::INSURANCE_FUND],
+        bump = insurance_fund.bump
+    )]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<DepositInsurance>, amount: u64) -> Result<()> {
+    require!(amount > 0, ErrorCode::InvalidInsuranceAmount);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.depositor.to_account_info(),
+                to: ctx.accounts.insurance_fund.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let insurance_fund = &mut ctx.accounts.insurance_fund;
+    insurance_fund.record_deposit(amount)?;
+
+    emit!(InsuranceFunded {
+        source: ctx.accounts.depositor.key(),
+        amount,
+        total_deposited: insurance_fund.total_deposited,
+    });
+
+    Ok(())
+}
+
+/// Lamports paid into the insurance fund, by a depositor or by the fee vault split
+#[event]
+pub struct InsuranceFunded {
+    pub source: Pubkey,
+    pub amount: u64,
+    pub total_deposited: u64,
+}
@@ -0,0 +1,217 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_2022::spl_token_2022::onchain::invoke_transfer_checked;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::{
+    GameConfig, FeeVault, InsuranceFund, Jackpot, ErrorCode, seeds,
+    instructions::deposit_insurance::InsuranceFunded, utils::fee_split::split_fees
+};
+
+/// Permissionless crank: pays out the fee vault's balance
+/// Remaining accounts are one payee per recipient with a share, in slot
+/// order (the wallet for lamports, its token account for `mint`), followed
+/// by any accounts a transfer-hook mint needs
+#[derive(Accounts)]
+pub struct DistributeFees<'info> {
+    #[account(
+        seeds = [seeds::GAME_CONFIG],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [seeds::FEE_VAULT],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+
+    /// CHECK: Treasury, receives the unallocated share
+    #[account(
+        mut,
+        constraint = treasury.key() == config.treasury
+    )]
+    pub treasury: AccountInfo<'info>,
+
+    pub caller: Signer<'info>,
+
+    /// Mint of the token fees to distribute (None = lamports)
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Fee vault's associated token account for `mint`
+    #[account(mut)]
+    pub fee_vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Treasury's token account for `mint`
+    #[account(mut)]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, DistributeFees<'info>>) -> Result<()> {
+    let recipients = ctx.accounts.fee_vault.active_recipients();
+    require!(
+        ctx.remaining_accounts.len() >= recipients.len(),
+        ErrorCode::InvalidFeeRecipient
+    );
+    let (payees, hook_accounts) = ctx.remaining_accounts.split_at(recipients.len());
+    let shares_bps: Vec<u16> = recipients.iter().map(|recipient| recipient.share_bps).collect();
+
+    let fee_vault_info = ctx.accounts.fee_vault.to_account_info();
+    let (amount, shares, treasury_amount) = match &ctx.accounts.mint {
+        None => {
+            // Lamports above the vault's rent-exempt minimum
+            let amount = fee_vault_info.lamports()
+                .saturating_sub(Rent::get()?.minimum_balance(fee_vault_info.data_len()));
+            require!(amount > 0, ErrorCode::NoFeesToDistribute);
+            let (shares, treasury_amount) = split_fees(amount, &shares_bps);
+
+            // The insurance fund and the jackpot book the slice they are sent
+            let insurance_fund_key = Pubkey::find_program_address(&[seeds::INSURANCE_FUND], &crate::ID).0;
+            let jackpot_key = Pubkey::find_program_address(&[seeds::JACKPOT], &crate::ID).0;
+
+            for ((recipient, payee), &share) in recipients.iter().zip(payees).zip(&shares) {
+                require_keys_eq!(payee.key(), recipient.recipient, ErrorCode::InvalidFeeRecipient);
+                if share > 0 {
+                    **fee_vault_info.try_borrow_mut_lamports()? -= share;
+                    **payee.try_borrow_mut_lamports()? += share;
+
+                    if payee.key() == insurance_fund_key {
+                        record_insurance_inflow(payee, fee_vault_info.key(), share)?;
+                    } else if payee.key() == jackpot_key {
+                        record_jackpot_inflow(payee, share)?;
+                    }
+                }
+            }
+            if treasury_amount > 0 {
+                **fee_vault_info.try_borrow_mut_lamports()? -= treasury_amount;
+                **ctx.accounts.treasury.try_borrow_mut_lamports()? += treasury_amount;
+            }
+
+            let fee_vault = &mut ctx.accounts.fee_vault;
+            fee_vault.total_distributed = fee_vault.total_distributed.checked_add(amount)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+            (amount, shares, treasury_amount)
+        },
+        Some(mint) => {
+            let token_program = ctx.accounts.token_program.as_ref()
+                .ok_or(ErrorCode::InvalidTokenAccount)?;
+            let vault_tokens = ctx.accounts.fee_vault_token_account.as_ref()
+                .ok_or(ErrorCode::InvalidTokenAccount)?;
+            require_keys_eq!(
+                vault_tokens.key(),
+                get_associated_token_address_with_program_id(
+                    &fee_vault_info.key(),
+                    &mint.key(),
+                    &token_program.key()
+                ),
+                ErrorCode::InvalidTokenAccount
+            );
+            let treasury_tokens = ctx.accounts.treasury_token_account.as_ref()
+                .ok_or(ErrorCode::InvalidTokenAccount)?;
+            require!(
+                treasury_tokens.owner == ctx.accounts.config.treasury &&
+                treasury_tokens.mint == mint.key(),
+                ErrorCode::InvalidTokenAccount
+            );
+
+            let amount = vault_tokens.amount;
+            require!(amount > 0, ErrorCode::NoFeesToDistribute);
+            let (shares, treasury_amount) = split_fees(amount, &shares_bps);
+
+            let fee_vault_seeds = &[seeds::FEE_VAULT, &[ctx.accounts.fee_vault.bump]];
+            let signer_seeds = &[&fee_vault_seeds[..]];
+            let transfer = |to: AccountInfo<'info>, share: u64| {
+                invoke_transfer_checked(
+                    token_program.key,
+                    vault_tokens.to_account_info(),
+                    mint.to_account_info(),
+                    to,
+                    fee_vault_info.clone(),
+                    hook_accounts,
+                    share,
+                    mint.decimals,
+                    signer_seeds,
+                )
+            };
+
+            for ((recipient, payee), &share) in recipients.iter().zip(payees).zip(&shares) {
+                require_keys_eq!(*payee.owner, token_program.key(), ErrorCode::InvalidFeeRecipient);
+                let payee_tokens = TokenAccount::try_deserialize(&mut &payee.try_borrow_data()?[..])?;
+                require!(
+                    payee_tokens.owner == recipient.recipient && payee_tokens.mint == mint.key(),
+                    ErrorCode::InvalidFeeRecipient
+                );
+                if share > 0 {
+                    transfer(payee.clone(), share)?;
+                }
+            }
+            if treasury_amount > 0 {
+                transfer(treasury_tokens.to_account_info(), treasury_amount)?;
+            }
+
+            (amount, shares, treasury_amount)
+        },
+    };
+
+    emit!(FeesDistributed {
+        mint: ctx.accounts.mint.as_ref().map(|mint| mint.key()),
+        amount,
+        recipients: recipients.iter().map(|recipient| recipient.recipient).collect(),
+        shares,
+        treasury_amount,
+        distributed_by: ctx.accounts.caller.key(),
+    });
+
+    Ok(())
+}
+
+/// Count a slice sent to the insurance fund PDA as a deposit
+fn record_insurance_inflow(payee: &AccountInfo, source: Pubkey, amount: u64) -> Result<()> {
+    let mut insurance_fund = InsuranceFund::try_deserialize(&mut &payee.try_borrow_data()?[..])
+        .map_err(|_| ErrorCode::InvalidFeeRecipient)?;
+    insurance_fund.record_deposit(amount)?;
+    insurance_fund.try_serialize(&mut &mut payee.try_borrow_mut_data()?[..])?;
+
+    emit!(InsuranceFunded {
+        source,
+        amount,
+        total_deposited: insurance_fund.total_deposited,
+    });
+
+    Ok(())
+}
+
+/// Count a slice sent to the jackpot PDA as funding
+fn record_jackpot_inflow(payee: &AccountInfo, amount: u64) -> Result<()> {
+    let mut jackpot = Jackpot::try_deserialize(&mut &payee.try_borrow_data()?[..])
+        .map_err(|_| ErrorCode::InvalidFeeRecipient)?;
+    jackpot.record_funding(amount)?;
+    jackpot.try_serialize(&mut &mut payee.try_borrow_mut_data()?[..])?;
+
+    emit!(JackpotFunded {
+        amount,
+        total_funded: jackpot.total_funded,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct JackpotFunded {
+    pub amount: u64,
+    pub total_funded: u64,
+}
+
+#[event]
+pub struct FeesDistributed {
+    /// Token fees' mint (None = lamports)
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    pub recipients: Vec<Pubkey>,
+    pub shares: Vec<u64>,
+    pub treasury_amount: u64,
+    pub distributed_by: Pubkey,
+}
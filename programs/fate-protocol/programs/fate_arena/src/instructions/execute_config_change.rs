@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use crate::{
+    GameConfig, FeeVault, QueuedAction, ConfigAction, ErrorCode, seeds,
+    utils::fee_split::validate_fee_split
+};
+
+#[derive(Accounts)]
+pub struct ExecuteConfigChange<'info> {
+    #[account(
+        mut,
+        seeds = [seeds::GAME_CONFIG],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [seeds::QUEUED_ACTION, queued_action.action_id.to_le_bytes().as_ref()],
+        bump = queued_action.bump,
+        has_one = proposer
+    )]
+    pub queued_action: Account<'info, QueuedAction>,
+
+    /// CHECK: Receives the queued action rent; validated by has_one
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+
+    /// Anyone can execute once the ETA has passed
+    pub executor: Signer<'info>,
+
+    /// Fee vault (required for `SetFeeRecipient`)
+    #[account(
+        mut,
+        seeds = [seeds::FEE_VAULT],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Option<Account<'info, FeeVault>>,
+}
+
+pub fn handler(ctx: Context<ExecuteConfigChange>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let queued_action = &ctx.accounts.queued_action;
+    let clock = Clock::get()?;
+
+    require!(
+        queued_action.is_ready(clock.unix_timestamp),
+        ErrorCode::TimelockNotElapsed
+    );
+
+    match queued_action.action {
+        ConfigAction::SetProtocolFee { protocol_fee_bps } => {
+            config.protocol_fee_bps = protocol_fee_bps;
+        },
+        ConfigAction::SetTreasury { treasury } => {
+            config.treasury = treasury;
+        },
+        ConfigAction::SetAdminDelay { admin_delay } => {
+            config.admin_delay = admin_delay;
+        },
+        ConfigAction::SetLobbyTimeout { lobby_timeout } => {
+            config.lobby_timeout = lobby_timeout;
+        },
+        ConfigAction::SetKeeperReward { keeper_reward } => {
+            config.keeper_reward = keeper_reward;
+        },
+        ConfigAction::SetSolUsdFeed { sol_usd_feed } => {
+            config.sol_usd_feed = sol_usd_feed;
+        },
+        ConfigAction::SetFeeTier { index, fee_tier } => {
+            config.fee_tiers[index as usize] = fee_tier;
+        },
+        ConfigAction::SetReferralShare { referral_share_bps } => {
+            config.referral_share_bps = referral_share_bps;
+        },
+        ConfigAction::SetMaxCreatorFee { max_creator_fee_bps } => {
+            config.max_creator_fee_bps = max_creator_fee_bps;
+        },
+        ConfigAction::SetFeeRecipient { index, fee_recipient } => {
+            let fee_vault = ctx.accounts.fee_vault.as_mut()
+                .ok_or(ErrorCode::InvalidFeeVault)?;
+            fee_vault.recipients[index as usize] = fee_recipient;
+
+            // Shares set in other slots still count toward 100%
+            let shares: Vec<u16> = fee_vault.recipients.iter()
+                .map(|recipient| recipient.share_bps)
+                .collect();
+            require!(validate_fee_split(&shares), ErrorCode::InvalidFeeSplit);
+        },
+        ConfigAction::SetJackpotPayout { jackpot_payout_bps } => {
+            config.jackpot_payout_bps = jackpot_payout_bps;
+        },
+    }
+
+    emit!(ConfigChangeExecuted {
+        action_id: queued_action.action_id,
+        action: queued_action.action,
+        executed_by: ctx.accounts.executor.key(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ConfigChangeExecuted {
+    pub action_id: u64,
+    pub action: ConfigAction,
+    pub executed_by: Pubkey,
+}
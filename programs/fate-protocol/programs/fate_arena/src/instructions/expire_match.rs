@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::{
+    GameConfig, Match, MatchStatus, ErrorCode, seeds
+};
+
+#[derive(Accounts)]
+pub struct ExpireMatch<'info> {
+    #[account(
+        mut,
+        seeds = [seeds::GAME_CONFIG],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [seeds::MATCH, match_account.match_id.to_le_bytes().as_ref()],
+        bump = match_account.bump,
+        constraint = match_account.status == MatchStatus::Open @ ErrorCode::InvalidMatchStatus
+    )]
+    pub match_account: Account<'info, Match>,
+
+    /// Anyone can expire a stale lobby and collect the keeper reward
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ExpireMatch>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let match_account = &mut ctx.accounts.match_account;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp >= match_account.lobby_expires_at(config.effective_lobby_timeout()),
+        ErrorCode::LobbyNotExpired
+    );
+
+    match_account.status = MatchStatus::Cancelled;
+    match_account.resolved_at = Some(clock.unix_timestamp);
+
+    // Keeper reward comes from lamports deposited on the config account above
+    // its rent-exempt minimum; skip it rather than fail when underfunded
+    let config_info = ctx.accounts.config.to_account_info();
+    let available = config_info.lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(config_info.data_len()));
+    let keeper_reward = if available >= config.keeper_reward {
+        config.keeper_reward
+    } else {
+        0
+    };
+
+    if keeper_reward > 0 {
+        **config_info.try_borrow_mut_lamports()? -= keeper_reward;
+        **ctx.accounts.keeper.to_account_info().try_borrow_mut_lamports()? += keeper_reward;
+    }
+
+    emit!(MatchExpired {
+        match_id: match_account.match_id,
+        expired_by: ctx.accounts.keeper.key(),
+        current_players: match_account.current_players,
+        keeper_reward,
+    });
+
+    // Players recover their stake through claim_refund
+
+    Ok(())
+}
+
+#[event]
+pub struct MatchExpired {
+    pub match_id: u64,
+    pub expired_by: Pubkey,
+    pub current_players: u8,
+    pub keeper_reward: u64,
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::{GameConfig, RoleAssignment, Role, ErrorCode, seeds};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct GrantRoleParams {
+    pub role: Role,
+}
+
+#[derive(Accounts)]
+#[instruction(params: GrantRoleParams)]
+pub struct GrantRole<'info> {
+    #[account(
+        seeds = [seeds::GAME_CONFIG],
+        bump = config.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = RoleAssignment::LEN,
+        seeds = [seeds::ROLE, params.role.seed(), holder.key().as_ref()],
+        bump
+    )]
+    pub role_assignment: Account<'info, RoleAssignment>,
+
+    /// CHECK: Any wallet can hold a role
+    pub holder: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<GrantRole>, params: GrantRoleParams) -> Result<()> {
+    let role_assignment = &mut ctx.accounts.role_assignment;
+
+    role_assignment.holder = ctx.accounts.holder.key();
+    role_assignment.role = params.role;
+    role_assignment.granted_by = ctx.accounts.authority.key();
+    role_assignment.granted_at = Clock::get()?.unix_timestamp;
+    role_assignment.bump = ctx.bumps.role_assignment;
+    role_assignment.version = RoleAssignment::VERSION;
+
+    emit!(RoleGranted {
+        holder: role_assignment.holder,
+        role: params.role,
+        granted_by: role_assignment.granted_by,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RoleGranted {
+    pub holder: Pubkey,
+    pub role: Role,
+    pub granted_by: Pubkey,
+}
@@ -1,9 +1,20 @@
 use anchor_lang::prelude::*;
-use crate::{GameConfig, ErrorCode, seeds, constants::BPS_DENOMINATOR};
+use crate::{
+    GameConfig, FeeTier, ErrorCode, seeds,
+    constants::{
+        DEFAULT_ADMIN_DELAY, MIN_ADMIN_DELAY, MAX_ADMIN_DELAY,
+        DEFAULT_LOBBY_TIMEOUT, MIN_LOBBY_TIMEOUT, MAX_LOBBY_TIMEOUT,
+        MAX_FEE_TIERS,
+    }
+};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct InitializeConfigParams {
     pub protocol_fee_bps: u16,
+    /// Timelock for config changes in seconds (default: 48 hours)
+    pub admin_delay: Option<i64>,
+    /// Time an Open match waits for players before it can be expired (default: 24 hours)
+    pub lobby_timeout: Option<i64>,
 }
 
 #[derive(Accounts)]
@@ -35,6 +46,18 @@ pub fn handler(ctx: Context<InitializeConfig>, params: InitializeConfigParams) -
         ErrorCode::InvalidProtocolFee
     );
 
+    let admin_delay = params.admin_delay.unwrap_or(DEFAULT_ADMIN_DELAY);
+    require!(
+        (MIN_ADMIN_DELAY..=MAX_ADMIN_DELAY).contains(&admin_delay),
+        ErrorCode::InvalidAdminDelay
+    );
+
+    let lobby_timeout = params.lobby_timeout.unwrap_or(DEFAULT_LOBBY_TIMEOUT);
+    require!(
+        (MIN_LOBBY_TIMEOUT..=MAX_LOBBY_TIMEOUT).contains(&lobby_timeout),
+        ErrorCode::InvalidLobbyTimeout
+    );
+
     config.authority = ctx.accounts.authority.key();
     config.treasury = ctx.accounts.treasury.key();
     config.protocol_fee_bps = params.protocol_fee_bps;
@@ -42,6 +65,16 @@ pub fn handler(ctx: Context<InitializeConfig>, params: InitializeConfigParams) -
     config.total_volume = 0;
     config.paused = false;
     config.bump = ctx.bumps.config;
+    config.version = GameConfig::VERSION;
+    config.admin_delay = admin_delay;
+    config.total_queued_actions = 0;
+    config.lobby_timeout = lobby_timeout;
+    config.keeper_reward = 0;
+    config.sol_usd_feed = Pubkey::default();
+    config.fee_tiers = [FeeTier::default(); MAX_FEE_TIERS];
+    config.referral_share_bps = 0;
+    config.max_creator_fee_bps = 0;
+    config.jackpot_payout_bps = 0;
 
     emit!(ConfigInitialized {
         authority: config.authority,
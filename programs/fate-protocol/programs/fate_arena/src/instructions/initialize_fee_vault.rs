@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::{GameConfig, FeeVault, FeeRecipient, ErrorCode, seeds, constants::MAX_FEE_RECIPIENTS};
+
+#[derive(Accounts)]
+pub struct InitializeFeeVault<'info> {
+    #[account(
+        seeds = [seeds::GAME_CONFIG],
+        bump = config.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = FeeVault::LEN,
+        seeds = [seeds::FEE_VAULT],
+        bump
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeFeeVault>) -> Result<()> {
+    let fee_vault = &mut ctx.accounts.fee_vault;
+
+    // No recipients yet: everything distributed goes to the treasury
+    fee_vault.recipients = [FeeRecipient::default(); MAX_FEE_RECIPIENTS];
+    fee_vault.total_distributed = 0;
+    fee_vault.bump = ctx.bumps.fee_vault;
+    fee_vault.version = FeeVault::VERSION;
+
+    emit!(FeeVaultInitialized {
+        fee_vault: fee_vault.key(),
+        authority: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct FeeVaultInitialized {
+    pub fee_vault: Pubkey,
+    pub authority: Pubkey,
+}
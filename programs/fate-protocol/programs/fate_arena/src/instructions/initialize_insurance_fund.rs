@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::{GameConfig, InsuranceFund, ErrorCode, seeds};
+
+#[derive(Accounts)]
+pub struct InitializeInsuranceFund<'info> {
+    #[account(
+        seeds = [seeds::GAME_CONFIG],
+        bump = config.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = InsuranceFund::LEN,
+        seeds = [seeds::INSURANCE_FUND],
+        bump
+    )]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeInsuranceFund>) -> Result<()> {
+    let insurance_fund = &mut ctx.accounts.insurance_fund;
+
+    insurance_fund.total_deposited = 0;
+    insurance_fund.total_covered = 0;
+    insurance_fund.shortfalls_covered = 0;
+    insurance_fund.bump = ctx.bumps.insurance_fund;
+    insurance_fund.version = InsuranceFund::VERSION;
+
+    emit!(InsuranceFundInitialized {
+        insurance_fund: insurance_fund.key(),
+        authority: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct InsuranceFundInitialized {
+    pub insurance_fund: Pubkey,
+    pub authority: Pubkey,
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::{GameConfig, Jackpot, ErrorCode, seeds};
+
+#[derive(Accounts)]
+pub struct InitializeJackpot<'info> {
+    #[account(
+        seeds = [seeds::GAME_CONFIG],
+        bump = config.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Jackpot::LEN,
+        seeds = [seeds::JACKPOT],
+        bump
+    )]
+    pub jackpot: Account<'info, Jackpot>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeJackpot>) -> Result<()> {
+    let jackpot = &mut ctx.accounts.jackpot;
+
+    jackpot.total_funded = 0;
+    jackpot.total_awarded = 0;
+    jackpot.total_claimed = 0;
+    jackpot.hits = 0;
+    jackpot.bump = ctx.bumps.jackpot;
+    jackpot.version = Jackpot::VERSION;
+
+    emit!(JackpotInitialized {
+        jackpot: jackpot.key(),
+        authority: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct JackpotInitialized {
+    pub jackpot: Pubkey,
+    pub authority: Pubkey,
+}
@@ -1,9 +1,18 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::{
-    Match, PlayerEntry, UserProfile, MatchStatus, ErrorCode, seeds
+    Match, PlayerEntry, UserProfile, MatchStatus, ErrorCode, seeds,
+    constants::MAX_PASSPHRASE_LEN, utils::escrow::Escrow, utils::pyth::get_usd_entry_fee_lamports
 };
 
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct JoinMatchParams {
+    /// Passphrase for passphrase-protected private matches
+    pub passphrase: Option<String>,
+    /// Slippage bound on the converted stake (required for USD-pegged matches)
+    pub max_entry_lamports: Option<u64>,
+}
+
 #[derive(Accounts)]
 pub struct JoinMatch<'info> {
     #[account(
@@ -47,40 +56,93 @@ pub struct JoinMatch<'info> {
     pub player: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Match's stake mint (token matches only)
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Match's token vault (token matches only)
+    #[account(mut)]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Player's token account (token matches only)
+    #[account(mut)]
+    pub player_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// CHECK: SOL/USD price account (USD-pegged matches only), checked
+    /// against the feed recorded on the match
+    pub sol_usd_price_update: Option<UncheckedAccount<'info>>,
 }
 
-pub fn handler(ctx: Context<JoinMatch>) -> Result<()> {
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, JoinMatch<'info>>, params: JoinMatchParams) -> Result<()> {
     let match_account = &mut ctx.accounts.match_account;
     let player_entry = &mut ctx.accounts.player_entry;
     let user_profile = &mut ctx.accounts.user_profile;
     let clock = Clock::get()?;
 
+    // Enforce private match access
+    if let Some(passphrase) = &params.passphrase {
+        require!(
+            passphrase.len() <= MAX_PASSPHRASE_LEN,
+            ErrorCode::InvalidAccessPolicy
+        );
+    }
+    require!(
+        match_account.admits(
+            &ctx.accounts.player.key(),
+            params.passphrase.as_deref().map(str::as_bytes)
+        ),
+        ErrorCode::NotInvited
+    );
+
+    // USD-pegged matches charge each joiner the current lamport equivalent
+    let entry_fee = match &match_account.usd_entry_fee {
+        Some(usd_entry_fee) => get_usd_entry_fee_lamports(
+            usd_entry_fee,
+            ctx.accounts.sol_usd_price_update.as_ref().map(|a| a.as_ref()),
+            params.max_entry_lamports,
+            &clock,
+        )?,
+        None => match_account.entry_fee,
+    };
+
     // Initialize player entry
     player_entry.match_account = match_account.key();
     player_entry.player = ctx.accounts.player.key();
     player_entry.prediction = None;
-    player_entry.amount_staked = match_account.entry_fee;
+    player_entry.amount_staked = entry_fee;
     player_entry.prediction_locked_at = None;
     player_entry.claimed = false;
     player_entry.winnings = 0;
     player_entry.bump = ctx.bumps.player_entry;
+    player_entry.version = PlayerEntry::VERSION;
+    player_entry.commitment = None;
+    player_entry.player_index = match_account.current_players;
+    player_entry.jackpot_award = 0;
+    user_profile.open_entry(player_entry)?;
 
-    // Transfer entry fee to vault
-    system_program::transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.player.to_account_info(),
-                to: ctx.accounts.vault.to_account_info(),
-            },
-        ),
-        match_account.entry_fee,
+    // Transfer entry fee to vault; settlement uses what the vault received
+    let received = Escrow::new(
+        match_account,
+        &ctx.accounts.vault,
+        ctx.bumps.vault,
+        ctx.accounts.mint.as_ref(),
+        ctx.accounts.vault_token_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.system_program,
+    )?.with_hook_accounts(ctx.remaining_accounts).deposit(
+        &ctx.accounts.player.to_account_info(),
+        ctx.accounts.player_token_account.as_ref(),
+        entry_fee,
     )?;
+    player_entry.amount_staked = received;
 
     // Update match
+    match_account.alive_mask |= 1 << player_entry.player_index;
     match_account.current_players = match_account.current_players.checked_add(1)
         .ok_or(ErrorCode::ArithmeticOverflow)?;
-    match_account.total_pot = match_account.total_pot.checked_add(match_account.entry_fee)
+    match_account.total_pot = match_account.total_pot.checked_add(received)
         .ok_or(ErrorCode::ArithmeticOverflow)?;
 
     // If match is now full, start it immediately
@@ -94,6 +156,7 @@ pub fn handler(ctx: Context<JoinMatch>) -> Result<()> {
         player: ctx.accounts.player.key(),
         current_players: match_account.current_players,
         match_full: match_account.is_full(),
+        amount_staked: received,
     });
 
     Ok(())
@@ -105,4 +168,6 @@ pub struct PlayerJoined {
     pub player: Pubkey,
     pub current_players: u8,
     pub match_full: bool,
+    /// Stake actually escrowed for this player
+    pub amount_staked: u64,
 }
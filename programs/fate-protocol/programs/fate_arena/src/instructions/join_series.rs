@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::{
+    Series, SeriesStatus, ErrorCode, seeds
+};
+
+#[derive(Accounts)]
+pub struct JoinSeries<'info> {
+    #[account(
+        mut,
+        seeds = [seeds::SERIES, series.series_id.to_le_bytes().as_ref()],
+        bump = series.bump,
+        constraint = series.status == SeriesStatus::Open @ ErrorCode::InvalidSeriesStatus,
+        constraint = series.player_a != player.key() @ ErrorCode::Unauthorized
+    )]
+    pub series: Account<'info, Series>,
+
+    /// CHECK: Series escrow vault
+    #[account(
+        mut,
+        seeds = [seeds::VAULT, series.key().as_ref()],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<JoinSeries>) -> Result<()> {
+    let series = &mut ctx.accounts.series;
+    let player = ctx.accounts.player.key();
+
+    if let Some(invited) = series.invited {
+        require_keys_eq!(player, invited, ErrorCode::NotInvited);
+    }
+
+    // Match the creator's stake
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.player.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+            },
+        ),
+        series.entry_fee,
+    )?;
+
+    series.player_b = Some(player);
+    series.status = SeriesStatus::InProgress;
+    series.total_pot = series.total_pot.checked_add(series.entry_fee)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(SeriesJoined {
+        series_id: series.series_id,
+        player_a: series.player_a,
+        player_b: player,
+        total_pot: series.total_pot,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct SeriesJoined {
+    pub series_id: u64,
+    pub player_a: Pubkey,
+    pub player_b: Pubkey,
+    pub total_pot: u64,
+}
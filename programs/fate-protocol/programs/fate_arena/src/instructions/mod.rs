@@ -2,18 +2,102 @@ pub mod initialize_config;
 pub mod create_market;
 pub mod create_match;
 pub mod join_match;
+pub mod leave_match;
 pub mod submit_prediction;
+pub mod commit_prediction;
+pub mod reveal_prediction;
 pub mod resolve_match;
+pub mod submit_round_prediction;
+pub mod resolve_round;
 pub mod claim_winnings;
 pub mod cancel_match;
+pub mod expire_match;
+pub mod claim_refund;
 pub mod update_user_profile;
+pub mod migrate_account;
+pub mod grant_role;
+pub mod revoke_role;
+pub mod set_paused;
+pub mod queue_config_change;
+pub mod execute_config_change;
+pub mod veto_config_change;
+pub mod set_market_active;
+pub mod set_market_limits;
+pub mod set_match_template;
+pub mod spawn_template_match;
+pub mod reclaim_house_bonus;
+pub mod create_tournament;
+pub mod register_tournament;
+pub mod start_tournament;
+pub mod create_bracket_match;
+pub mod advance_tournament;
+pub mod cancel_tournament;
+pub mod claim_tournament_prize;
+pub mod create_series;
+pub mod join_series;
+pub mod create_series_match;
+pub mod record_series_result;
+pub mod cancel_series;
+pub mod claim_series_prize;
+pub mod register_referrer;
+pub mod set_referrer;
+pub mod claim_referral_fees;
+pub mod initialize_fee_vault;
+pub mod distribute_fees;
+pub mod initialize_insurance_fund;
+pub mod deposit_insurance;
+pub mod cover_shortfall;
+pub mod initialize_jackpot;
+pub mod claim_jackpot;
 
 pub use initialize_config::*;
 pub use create_market::*;
 pub use create_match::*;
 pub use join_match::*;
+pub use leave_match::*;
 pub use submit_prediction::*;
+pub use commit_prediction::*;
+pub use reveal_prediction::*;
 pub use resolve_match::*;
+pub use submit_round_prediction::*;
+pub use resolve_round::*;
 pub use claim_winnings::*;
 pub use cancel_match::*;
+pub use expire_match::*;
+pub use claim_refund::*;
 pub use update_user_profile::*;
+pub use migrate_account::*;
+pub use grant_role::*;
+pub use revoke_role::*;
+pub use set_paused::*;
+pub use queue_config_change::*;
+pub use execute_config_change::*;
+pub use veto_config_change::*;
+pub use set_market_active::*;
+pub use set_market_limits::*;
+pub use set_match_template::*;
+pub use spawn_template_match::*;
+pub use reclaim_house_bonus::*;
+pub use create_tournament::*;
+pub use register_tournament::*;
+pub use start_tournament::*;
+pub use create_bracket_match::*;
+pub use advance_tournament::*;
+pub use cancel_tournament::*;
+pub use claim_tournament_prize::*;
+pub use create_series::*;
+pub use join_series::*;
+pub use create_series_match::*;
+pub use record_series_result::*;
+pub use cancel_series::*;
+pub use claim_series_prize::*;
+pub use register_referrer::*;
+pub use set_referrer::*;
+pub use claim_referral_fees::*;
+pub use initialize_fee_vault::*;
+pub use distribute_fees::*;
+pub use initialize_insurance_fund::*;
+pub use deposit_insurance::*;
+pub use cover_shortfall::*;
+pub use initialize_jackpot::*;
+pub use claim_jackpot::*;
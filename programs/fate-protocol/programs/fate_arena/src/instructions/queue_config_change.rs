@@ -0,0 +1,153 @@
+use anchor_lang::prelude::*;
+use crate::{
+    GameConfig, QueuedAction, RoleAssignment, ConfigAction, Role,
+    ErrorCode, seeds, constants::*
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct QueueConfigChangeParams {
+    pub action: ConfigAction,
+    /// Earliest execution time; must be at least `admin_delay` from now
+    pub eta: i64,
+}
+
+#[derive(Accounts)]
+pub struct QueueConfigChange<'info> {
+    #[account(
+        mut,
+        seeds = [seeds::GAME_CONFIG],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = QueuedAction::LEN,
+        seeds = [seeds::QUEUED_ACTION, config.total_queued_actions.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub queued_action: Account<'info, QueuedAction>,
+
+    /// FeeManager role of the proposer (not needed for the config authority)
+    #[account(
+        seeds = [seeds::ROLE, Role::FeeManager.seed(), proposer.key().as_ref()],
+        bump = role_assignment.bump
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<QueueConfigChange>, params: QueueConfigChangeParams) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let queued_action = &mut ctx.accounts.queued_action;
+    let proposer = ctx.accounts.proposer.key();
+    let clock = Clock::get()?;
+
+    match params.action {
+        ConfigAction::SetProtocolFee { protocol_fee_bps } => {
+            require!(
+                protocol_fee_bps <= GameConfig::MAX_PROTOCOL_FEE_BPS,
+                ErrorCode::InvalidProtocolFee
+            );
+        },
+        ConfigAction::SetTreasury { .. } => {},
+        ConfigAction::SetAdminDelay { admin_delay } => {
+            require!(
+                (MIN_ADMIN_DELAY..=MAX_ADMIN_DELAY).contains(&admin_delay),
+                ErrorCode::InvalidAdminDelay
+            );
+        },
+        ConfigAction::SetLobbyTimeout { lobby_timeout } => {
+            require!(
+                (MIN_LOBBY_TIMEOUT..=MAX_LOBBY_TIMEOUT).contains(&lobby_timeout),
+                ErrorCode::InvalidLobbyTimeout
+            );
+        },
+        ConfigAction::SetKeeperReward { keeper_reward } => {
+            require!(
+                keeper_reward <= MAX_KEEPER_REWARD,
+                ErrorCode::InvalidKeeperReward
+            );
+        },
+        ConfigAction::SetSolUsdFeed { .. } => {},
+        ConfigAction::SetReferralShare { referral_share_bps } => {
+            require!(
+                referral_share_bps <= MAX_REFERRAL_SHARE_BPS,
+                ErrorCode::InvalidReferralShare
+            );
+        },
+        ConfigAction::SetMaxCreatorFee { max_creator_fee_bps } => {
+            require!(
+                max_creator_fee_bps <= MAX_CREATOR_FEE_BPS,
+                ErrorCode::InvalidCreatorFee
+            );
+        },
+        ConfigAction::SetFeeRecipient { index, fee_recipient } => {
+            require!(
+                (index as usize) < MAX_FEE_RECIPIENTS &&
+                fee_recipient.share_bps <= BPS_DENOMINATOR,
+                ErrorCode::InvalidFeeSplit
+            );
+        },
+        ConfigAction::SetFeeTier { index, fee_tier } => {
+            require!(
+                (index as usize) < MAX_FEE_TIERS &&
+                fee_tier.discount_bps <= BPS_DENOMINATOR,
+                ErrorCode::InvalidFeeTier
+            );
+        },
+        ConfigAction::SetJackpotPayout { jackpot_payout_bps } => {
+            require!(
+                jackpot_payout_bps <= BPS_DENOMINATOR,
+                ErrorCode::InvalidJackpotPayout
+            );
+        },
+    }
+
+    // Economic changes can be delegated to fee managers; the rest stays with the authority
+    let authorized = match params.action.delegated_role() {
+        Some(role) => config.has_role(&proposer, role, ctx.accounts.role_assignment.as_deref()),
+        None => proposer == config.authority,
+    };
+    require!(authorized, ErrorCode::Unauthorized);
+
+    let earliest_eta = clock.unix_timestamp
+        .checked_add(config.effective_admin_delay())
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    require!(params.eta >= earliest_eta, ErrorCode::InvalidTimelockEta);
+
+    let action_id = config.total_queued_actions;
+
+    queued_action.action_id = action_id;
+    queued_action.action = params.action;
+    queued_action.proposer = proposer;
+    queued_action.eta = params.eta;
+    queued_action.created_at = clock.unix_timestamp;
+    queued_action.bump = ctx.bumps.queued_action;
+    queued_action.version = QueuedAction::VERSION;
+
+    config.total_queued_actions = config.total_queued_actions.checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(ConfigChangeQueued {
+        action_id,
+        action: params.action,
+        proposer,
+        eta: params.eta,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ConfigChangeQueued {
+    pub action_id: u64,
+    pub action: ConfigAction,
+    pub proposer: Pubkey,
+    pub eta: i64,
+}
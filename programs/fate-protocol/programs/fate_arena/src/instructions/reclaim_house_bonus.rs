@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use crate::{
+    Market, Match, MatchStatus, ErrorCode, seeds
+};
+
+/// Permissionless: returns a template's house bonus to the market when its
+/// match was cancelled or ended in a refund, so the bonus isn't stranded
+#[derive(Accounts)]
+pub struct ReclaimHouseBonus<'info> {
+    #[account(
+        mut,
+        seeds = [seeds::MARKET, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        seeds = [seeds::MATCH, match_account.match_id.to_le_bytes().as_ref()],
+        bump = match_account.bump,
+        has_one = market,
+        constraint = match_account.house_bonus > 0 @ ErrorCode::NoHouseBonus,
+        constraint = match_account.status == MatchStatus::Cancelled ||
+                     (match_account.status == MatchStatus::Completed &&
+                      match_account.winning_side.is_none()) @ ErrorCode::InvalidMatchStatus
+    )]
+    pub match_account: Account<'info, Match>,
+
+    /// CHECK: Match escrow vault
+    #[account(
+        mut,
+        seeds = [seeds::VAULT, match_account.key().as_ref()],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    pub caller: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ReclaimHouseBonus>) -> Result<()> {
+    let match_account = &mut ctx.accounts.match_account;
+    let amount = match_account.house_bonus;
+
+    let match_key = match_account.key();
+    let vault_seeds = &[
+        seeds::VAULT,
+        match_key.as_ref(),
+        &[ctx.bumps.vault],
+    ];
+    let signer_seeds = &[&vault_seeds[..]];
+
+    anchor_lang::system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.market.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    match_account.house_bonus = 0;
+
+    emit!(HouseBonusReclaimed {
+        market_id: ctx.accounts.market.market_id,
+        match_id: match_account.match_id,
+        amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct HouseBonusReclaimed {
+    pub market_id: u64,
+    pub match_id: u64,
+    pub amount: u64,
+}
@@ -0,0 +1,197 @@
+use anchor_lang::prelude::*;
+use crate::{
+    GameConfig, FeeVault, Match, PlayerEntry, Series, MatchStatus, SeriesStatus,
+    ErrorCode, seeds, constants::*,
+    utils::bracket::Contender,
+    utils::series::{decide_round, wins_needed}
+};
+use super::SeriesCancelled;
+
+/// Permissionless crank: scores the round in play and settles the series
+/// once one player has a majority of rounds
+#[derive(Accounts)]
+pub struct RecordSeriesResult<'info> {
+    #[account(
+        mut,
+        seeds = [seeds::GAME_CONFIG],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [seeds::SERIES, series.series_id.to_le_bytes().as_ref()],
+        bump = series.bump,
+        constraint = series.status == SeriesStatus::InProgress @ ErrorCode::InvalidSeriesStatus
+    )]
+    pub series: Account<'info, Series>,
+
+    #[account(
+        seeds = [seeds::MATCH, match_account.match_id.to_le_bytes().as_ref()],
+        bump = match_account.bump,
+        constraint = series.current_match_id == Some(match_account.match_id) @ ErrorCode::SeriesMatch
+    )]
+    pub match_account: Account<'info, Match>,
+
+    #[account(
+        seeds = [seeds::PLAYER_ENTRY, match_account.key().as_ref(), series.player_a.as_ref()],
+        bump = entry_a.bump
+    )]
+    pub entry_a: Account<'info, PlayerEntry>,
+
+    #[account(
+        seeds = [seeds::PLAYER_ENTRY, match_account.key().as_ref(), entry_b.player.as_ref()],
+        bump = entry_b.bump,
+        constraint = series.player_b == Some(entry_b.player) @ ErrorCode::NotSeriesPlayer
+    )]
+    pub entry_b: Account<'info, PlayerEntry>,
+
+    /// CHECK: Series escrow vault
+    #[account(
+        mut,
+        seeds = [seeds::VAULT, series.key().as_ref()],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    /// Protocol fee vault (required when a protocol fee is charged)
+    #[account(
+        mut,
+        seeds = [seeds::FEE_VAULT],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Option<Account<'info, FeeVault>>,
+
+    pub caller: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RecordSeriesResult>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let series = &mut ctx.accounts.series;
+    let child = &ctx.accounts.match_account;
+    let clock = Clock::get()?;
+
+    // A round nobody predicted in is replayed once its window closes
+    let resolved = match child.status {
+        MatchStatus::Completed => true,
+        MatchStatus::Open | MatchStatus::Cancelled
+            if child.start_price.is_none() &&
+               clock.unix_timestamp > child.created_at + child.prediction_window => false,
+        _ => return err!(ErrorCode::RoundNotFinished),
+    };
+
+    let contenders = [&ctx.accounts.entry_a, &ctx.accounts.entry_b].map(|entry| Contender {
+        seed: entry.player_index,
+        prediction: entry.prediction,
+        locked_at: entry.prediction_locked_at,
+    });
+
+    let round_winner = match decide_round(&contenders[0], &contenders[1], resolved, child.winning_side) {
+        Some(true) => {
+            series.wins_a += 1;
+            Some(series.player_a)
+        }
+        Some(false) => {
+            series.wins_b += 1;
+            series.player_b
+        }
+        None => None,
+    };
+    series.current_match_id = None;
+
+    emit!(SeriesRoundRecorded {
+        series_id: series.series_id,
+        match_id: child.match_id,
+        round_winner,
+        wins_a: series.wins_a,
+        wins_b: series.wins_b,
+    });
+
+    let needed = wins_needed(series.best_of);
+    let winner = if series.wins_a >= needed {
+        series.player_a
+    } else if series.wins_b >= needed {
+        series.player_b.ok_or(ErrorCode::NotSeriesPlayer)?
+    } else {
+        // Too many replays: give up on the series and refund both stakes
+        if series.matches_played >= series.best_of.saturating_mul(SERIES_REPLAY_FACTOR) {
+            series.status = SeriesStatus::Cancelled;
+            series.completed_at = Some(clock.unix_timestamp);
+
+            emit!(SeriesCancelled {
+                series_id: series.series_id,
+                wins_a: series.wins_a,
+                wins_b: series.wins_b,
+                refund_total: series.total_pot,
+            });
+        }
+        return Ok(());
+    };
+
+    // Series decided: take the protocol fee once and lock in the prize pool
+    let protocol_fee = (series.total_pot as u128 * series.fee_bps(config.protocol_fee_bps) as u128 / 10000) as u64;
+    series.prize_pool = series.total_pot - protocol_fee;
+    series.winner = Some(winner);
+    series.status = SeriesStatus::Completed;
+    series.completed_at = Some(clock.unix_timestamp);
+
+    if protocol_fee > 0 {
+        let fee_vault = ctx.accounts.fee_vault.as_ref().ok_or(ErrorCode::InvalidFeeVault)?;
+        let series_key = series.key();
+        let vault_seeds = &[
+            seeds::VAULT,
+            series_key.as_ref(),
+            &[ctx.bumps.vault],
+        ];
+        let signer_seeds = &[&vault_seeds[..]];
+
+        anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: fee_vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            protocol_fee,
+        )?;
+    }
+
+    config.total_volume = config.total_volume
+        .checked_add(series.total_pot)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(SeriesCompleted {
+        series_id: series.series_id,
+        winner,
+        wins_a: series.wins_a,
+        wins_b: series.wins_b,
+        prize_pool: series.prize_pool,
+        protocol_fee,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct SeriesRoundRecorded {
+    pub series_id: u64,
+    pub match_id: u64,
+    /// None when the round was replayed
+    pub round_winner: Option<Pubkey>,
+    pub wins_a: u8,
+    pub wins_b: u8,
+}
+
+#[event]
+pub struct SeriesCompleted {
+    pub series_id: u64,
+    pub winner: Pubkey,
+    pub wins_a: u8,
+    pub wins_b: u8,
+    pub prize_pool: u64,
+    pub protocol_fee: u64,
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::{Referrer, seeds};
+
+#[derive(Accounts)]
+pub struct RegisterReferrer<'info> {
+    #[account(
+        init,
+        payer = referrer,
+        space = Referrer::LEN,
+        seeds = [seeds::REFERRER, referrer.key().as_ref()],
+        bump
+    )]
+    pub referrer_account: Account<'info, Referrer>,
+
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RegisterReferrer>) -> Result<()> {
+    let referrer_account = &mut ctx.accounts.referrer_account;
+    let clock = Clock::get()?;
+
+    referrer_account.referrer = ctx.accounts.referrer.key();
+    referrer_account.referred_count = 0;
+    referrer_account.total_accrued = 0;
+    referrer_account.total_claimed = 0;
+    referrer_account.created_at = clock.unix_timestamp;
+    referrer_account.bump = ctx.bumps.referrer_account;
+    referrer_account.version = Referrer::VERSION;
+
+    emit!(ReferrerRegistered {
+        referrer: referrer_account.referrer,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ReferrerRegistered {
+    pub referrer: Pubkey,
+}
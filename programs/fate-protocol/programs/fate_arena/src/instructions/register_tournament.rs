@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::{
+    Tournament, UserProfile, TournamentStatus, ErrorCode, seeds
+};
+
+#[derive(Accounts)]
+pub struct RegisterTournament<'info> {
+    #[account(
+        mut,
+        seeds = [seeds::TOURNAMENT, tournament.tournament_id.to_le_bytes().as_ref()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::Registration @ ErrorCode::InvalidTournamentStatus,
+        constraint = !tournament.is_full() @ ErrorCode::TournamentFull
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Player profile (level is used for seeding)
+    #[account(
+        seeds = [seeds::USER_PROFILE, player.key().as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    /// CHECK: Tournament escrow vault
+    #[account(
+        mut,
+        seeds = [seeds::VAULT, tournament.key().as_ref()],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RegisterTournament>) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let player = ctx.accounts.player.key();
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp <= tournament.registration_ends_at,
+        ErrorCode::RegistrationClosed
+    );
+    require!(
+        tournament.player_index(&player).is_none(),
+        ErrorCode::AlreadyRegistered
+    );
+
+    // Transfer entry fee to vault
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.player.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+            },
+        ),
+        tournament.entry_fee,
+    )?;
+
+    tournament.players.push(player);
+    tournament.seed_levels.push(ctx.accounts.user_profile.level);
+    tournament.eliminated_in.push(0);
+    tournament.total_pot = tournament.total_pot.checked_add(tournament.entry_fee)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(TournamentRegistered {
+        tournament_id: tournament.tournament_id,
+        player,
+        level: ctx.accounts.user_profile.level,
+        registered: tournament.players.len() as u8,
+        full: tournament.is_full(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct TournamentRegistered {
+    pub tournament_id: u64,
+    pub player: Pubkey,
+    pub level: u16,
+    pub registered: u8,
+    pub full: bool,
+}
@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
 use crate::{
     GameConfig, Market, Match, MatchStatus, PredictionSide,
-    ErrorCode, seeds, utils::pyth::*,
+    ErrorCode, seeds, utils::pyth::*, utils::buckets::bucket_for_change,
+    utils::closest_price::rank_targets,
 };
 
 #[derive(Accounts)]
@@ -25,6 +26,7 @@ pub struct ResolveMatch<'info> {
         bump = match_account.bump,
         has_one = market,
         constraint = match_account.status == MatchStatus::InProgress @ ErrorCode::InvalidMatchStatus,
+        constraint = !match_account.is_elimination_mode() @ ErrorCode::EliminationMatch,
         constraint = match_account.can_resolve() @ ErrorCode::ResolutionTimeNotReached
     )]
     pub match_account: Account<'info, Match>,
@@ -33,6 +35,16 @@ pub struct ResolveMatch<'info> {
     pub price_update: AccountInfo<'info>,
 
     pub resolver: Signer<'info>,
+
+    /// Second market (relative-performance matches only)
+    #[account(
+        seeds = [seeds::MARKET, market_b.market_id.to_le_bytes().as_ref()],
+        bump = market_b.bump
+    )]
+    pub market_b: Option<Account<'info, Market>>,
+
+    /// CHECK: Pyth price account of market_b - key checked in get_second_market_price()
+    pub price_update_b: Option<UncheckedAccount<'info>>,
 }
 
 pub fn handler(ctx: Context<ResolveMatch>) -> Result<()> {
@@ -78,21 +90,93 @@ pub fn handler(ctx: Context<ResolveMatch>) -> Result<()> {
     // Compare prices to determine winner
     let price_comparison = compare_prices(start_price, end_price);
 
-    let winning_side = match price_comparison {
-        PriceComparison::Higher => {
-            msg!("Price went UP: {} -> {}", start_price, end_price);
-            Some(PredictionSide::Higher)
-        },
-        PriceComparison::Lower => {
-            msg!("Price went DOWN: {} -> {}", start_price, end_price);
-            Some(PredictionSide::Lower)
-        },
-        PriceComparison::Equal => {
-            // Edge case: price is exactly the same
-            // No winners, all players will get refunds in claim_winnings
-            msg!("Price stayed EQUAL: {} = {} (REFUND ALL)", start_price, end_price);
+    let winning_side = if let Some(market_b) = match_account.market_b {
+        let start_price_b = match_account.start_price_b.ok_or(ErrorCode::MatchNotStarted)?;
+        let price_update_b = ctx.accounts.price_update_b.as_ref().map(|a| a.to_account_info());
+        let end_price_b = get_second_market_price(
+            &market_b,
+            ctx.accounts.market_b.as_ref(),
+            price_update_b.as_ref(),
+            &clock,
+        )?;
+        match_account.end_price_b = Some(end_price_b);
+
+        match compare_performance(start_price, end_price, start_price_b, end_price_b) {
+            PriceComparison::Higher => {
+                msg!("A outperformed: {} -> {} vs {} -> {}", start_price, end_price, start_price_b, end_price_b);
+                Some(PredictionSide::AOutperforms)
+            },
+            PriceComparison::Lower => {
+                msg!("B outperformed: {} -> {} vs {} -> {}", start_price, end_price, start_price_b, end_price_b);
+                Some(PredictionSide::BOutperforms)
+            },
+            PriceComparison::Equal => {
+                msg!("Equal performance (REFUND ALL)");
+                None
+            },
+        }
+    } else if let Some(threshold) = match_account.volatility_threshold_bps {
+        let change_bps = price_change_bps(start_price, end_price);
+
+        if moved_beyond(start_price, end_price, threshold) {
+            msg!("Price moved {} bps, beyond {} bps", change_bps, threshold);
+            Some(PredictionSide::MovesBeyond)
+        } else {
+            msg!("Price moved {} bps, within {} bps", change_bps, threshold);
+            Some(PredictionSide::StaysWithin)
+        }
+    } else if match_account.is_closest_price_mode() && match_account.targets.is_empty() {
+        msg!("No targets to rank (REFUND ALL)");
+        None
+    } else if match_account.is_closest_price_mode() {
+        // Rank targets stored on the match; claims look up their tier
+        let (tier_distances, tier_counts) = rank_targets(
+            &match_account.targets,
+            end_price,
+            match_account.payout_tiers_bps.len(),
+        );
+        msg!("End price {}: paid tiers at distances {:?}", end_price, tier_distances);
+        match_account.tier_distances = tier_distances;
+        match_account.tier_counts = tier_counts;
+        Some(PredictionSide::TargetPrice(end_price))
+    } else if match_account.is_bucket_mode() {
+        let change_bps = price_change_bps(start_price, end_price);
+        let bucket = bucket_for_change(&match_account.bucket_bounds_bps, change_bps);
+
+        // Nobody picked the winning bucket: refund everyone
+        if match_account.bucket_counts[bucket as usize] > 0 {
+            msg!("Price changed {} bps: bucket {} wins", change_bps, bucket);
+            Some(PredictionSide::Bucket(bucket))
+        } else {
+            msg!("Price changed {} bps: bucket {} is empty (REFUND ALL)", change_bps, bucket);
+            None
+        }
+    } else {
+        match price_comparison {
+            PriceComparison::Higher => {
+                msg!("Price went UP: {} -> {}", start_price, end_price);
+                Some(PredictionSide::Higher)
+            },
+            PriceComparison::Lower => {
+                msg!("Price went DOWN: {} -> {}", start_price, end_price);
+                Some(PredictionSide::Lower)
+            },
+            PriceComparison::Equal => {
+                // Edge case: price is exactly the same
+                // No winners, all players will get refunds in claim_winnings
+                msg!("Price stayed EQUAL: {} = {} (REFUND ALL)", start_price, end_price);
+                None
+            },
+        }
+    };
+
+    // Nobody picked the winning side: refund everyone, as for an empty bucket
+    let winning_side = match winning_side {
+        Some(side) if match_account.side_count(side) == Some(0) => {
+            msg!("Nobody picked {:?} (REFUND ALL)", side);
             None
         },
+        winning_side => winning_side,
     };
 
     // Update match state
@@ -111,6 +195,7 @@ pub fn handler(ctx: Context<ResolveMatch>) -> Result<()> {
         start_price,
         end_price,
         winning_side,
+        end_price_b: match_account.end_price_b,
         total_pot: match_account.total_pot,
         price_change: if end_price > start_price {
             (end_price - start_price) as i64
@@ -128,6 +213,8 @@ pub struct MatchResolved {
     pub start_price: u64,
     pub end_price: u64,
     pub winning_side: Option<PredictionSide>,
+    /// Second market's end price (relative-performance matches)
+    pub end_price_b: Option<u64>,
     pub total_pot: u64,
     pub price_change: i64,
 }
@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use crate::{
+    GameConfig, Market, Match, EliminationRound, MatchStatus, PredictionSide,
+    ErrorCode, seeds, utils::pyth::*, utils::elimination::round_survivors,
+};
+
+/// Permissionless: closes the current elimination round, knocks out wrong
+/// calls and either opens the next round or completes the match
+#[derive(Accounts)]
+pub struct ResolveRound<'info> {
+    #[account(
+        mut,
+        seeds = [seeds::GAME_CONFIG],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        seeds = [seeds::MARKET, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        seeds = [seeds::MATCH, match_account.match_id.to_le_bytes().as_ref()],
+        bump = match_account.bump,
+        has_one = market,
+        constraint = match_account.is_elimination_mode() @ ErrorCode::InvalidMatchType,
+        constraint = match_account.status == MatchStatus::InProgress @ ErrorCode::InvalidMatchStatus
+    )]
+    pub match_account: Account<'info, Match>,
+
+    /// CHECK: Pyth price update account - validated in handler via validate_price_feed() and get_pyth_price()
+    pub price_update: AccountInfo<'info>,
+
+    pub resolver: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ResolveRound>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let match_account = &mut ctx.accounts.match_account;
+    let market = &ctx.accounts.market;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp >= match_account.round_ends_at(),
+        ErrorCode::RoundNotOver
+    );
+
+    // Get end price from Pyth
+    let feed_id_hex = market.pyth_price_feed.to_string();
+    validate_price_feed(&ctx.accounts.price_update.to_account_info(), &feed_id_hex)?;
+    let pyth_price = get_pyth_price(
+        &ctx.accounts.price_update,
+        &feed_id_hex,
+        &clock,
+    )?;
+    require!(
+        pyth_price.is_confidence_acceptable(),
+        ErrorCode::ConfidenceIntervalTooWide
+    );
+    let end_price = pyth_price.normalized_price;
+
+    let round_number = match_account.current_round;
+    let start_price = match_account.rounds.last()
+        .ok_or(ErrorCode::MatchNotStarted)?
+        .start_price;
+
+    let winning_side = match compare_prices(start_price, end_price) {
+        PriceComparison::Higher => Some(PredictionSide::Higher),
+        PriceComparison::Lower => Some(PredictionSide::Lower),
+        PriceComparison::Equal => None,
+    };
+    let survivors = round_survivors(
+        match_account.alive_mask,
+        match_account.higher_mask,
+        match_account.lower_mask,
+        winning_side,
+    );
+
+    // Record the round
+    if let Some(round) = match_account.rounds.last_mut() {
+        round.end_price = Some(end_price);
+        round.winning_side = winning_side;
+        round.survivors = survivors;
+    }
+    let eliminated = match_account.alive_mask & !survivors;
+    match_account.alive_mask = survivors;
+    match_account.higher_mask = 0;
+    match_account.lower_mask = 0;
+
+    let finished = survivors.count_ones() <= 1 ||
+        round_number >= match_account.elimination_rounds;
+
+    if finished {
+        // Survivors split the pot through claim_winnings
+        match_account.end_price = Some(end_price);
+        match_account.winning_side = winning_side;
+        match_account.status = MatchStatus::Completed;
+        match_account.resolved_at = Some(clock.unix_timestamp);
+
+        config.total_volume = config.total_volume
+            .checked_add(match_account.total_pot)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+    } else {
+        // Next round starts where this one ended
+        match_account.current_round = round_number + 1;
+        match_account.round_started_at = clock.unix_timestamp;
+        match_account.rounds.push(EliminationRound {
+            start_price: end_price,
+            end_price: None,
+            winning_side: None,
+            survivors: 0,
+        });
+    }
+
+    msg!(
+        "Round {}: {} -> {}, {} survivor(s)",
+        round_number,
+        start_price,
+        end_price,
+        survivors.count_ones()
+    );
+
+    emit!(RoundResolved {
+        match_id: match_account.match_id,
+        round: round_number,
+        start_price,
+        end_price,
+        winning_side,
+        survivors,
+        eliminated,
+        finished,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RoundResolved {
+    pub match_id: u64,
+    pub round: u8,
+    pub start_price: u64,
+    pub end_price: u64,
+    pub winning_side: Option<PredictionSide>,
+    /// Players still in, by join index
+    pub survivors: u16,
+    /// Players knocked out this round, by join index
+    pub eliminated: u16,
+    pub finished: bool,
+}
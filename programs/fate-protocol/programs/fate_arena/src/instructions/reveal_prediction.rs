@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::{
+    Match, PlayerEntry, MatchStatus, PredictionSide, ErrorCode, seeds,
+    utils::sealed::prediction_commitment,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RevealPredictionParams {
+    pub prediction: PredictionSide,
+    pub salt: [u8; 32],
+}
+
+/// Reveals are accepted after the prediction window closes and before
+/// resolution_time. Commitments left unrevealed count as no prediction
+/// and cannot win, nor be refunded when nobody who revealed won
+#[derive(Accounts)]
+pub struct RevealPrediction<'info> {
+    #[account(
+        mut,
+        seeds = [seeds::MATCH, match_account.match_id.to_le_bytes().as_ref()],
+        bump = match_account.bump,
+        constraint = match_account.status == MatchStatus::InProgress @ ErrorCode::InvalidMatchStatus
+    )]
+    pub match_account: Account<'info, Match>,
+
+    #[account(
+        mut,
+        seeds = [
+            seeds::PLAYER_ENTRY,
+            match_account.key().as_ref(),
+            player.key().as_ref()
+        ],
+        bump = player_entry.bump,
+        constraint = player_entry.prediction.is_none() @ ErrorCode::PredictionAlreadyLocked
+    )]
+    pub player_entry: Account<'info, PlayerEntry>,
+
+    pub player: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RevealPrediction>, params: RevealPredictionParams) -> Result<()> {
+    let match_account = &mut ctx.accounts.match_account;
+    let player_entry = &mut ctx.accounts.player_entry;
+    let clock = Clock::get()?;
+
+    require!(
+        match_account.is_reveal_open(clock.unix_timestamp),
+        ErrorCode::RevealNotOpen
+    );
+
+    let commitment = player_entry.commitment.ok_or(ErrorCode::NoCommitment)?;
+    require!(
+        prediction_commitment(&params.prediction, &params.salt)? == commitment,
+        ErrorCode::CommitmentMismatch
+    );
+    require!(
+        match_account.accepts_prediction(params.prediction),
+        ErrorCode::InvalidPrediction
+    );
+
+    match_account.record_prediction(params.prediction)?;
+    if let Some(revealed_stake) = match_account.revealed_stake.as_mut() {
+        *revealed_stake = revealed_stake.checked_add(player_entry.amount_staked)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+    }
+    player_entry.prediction = Some(params.prediction);
+
+    emit!(PredictionRevealed {
+        match_id: match_account.match_id,
+        player: ctx.accounts.player.key(),
+        prediction: params.prediction,
+        locked_at: player_entry.prediction_locked_at.unwrap_or_default(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct PredictionRevealed {
+    pub match_id: u64,
+    pub player: Pubkey,
+    pub prediction: PredictionSide,
+    /// When the commitment was made
+    pub locked_at: i64,
+}
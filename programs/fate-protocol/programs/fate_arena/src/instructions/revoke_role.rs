@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::{GameConfig, RoleAssignment, Role, ErrorCode, seeds};
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    #[account(
+        seeds = [seeds::GAME_CONFIG],
+        bump = config.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            seeds::ROLE,
+            role_assignment.role.seed(),
+            role_assignment.holder.as_ref()
+        ],
+        bump = role_assignment.bump
+    )]
+    pub role_assignment: Account<'info, RoleAssignment>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RevokeRole>) -> Result<()> {
+    let role_assignment = &ctx.accounts.role_assignment;
+
+    emit!(RoleRevoked {
+        holder: role_assignment.holder,
+        role: role_assignment.role,
+        revoked_by: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub holder: Pubkey,
+    pub role: Role,
+    pub revoked_by: Pubkey,
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::{GameConfig, Market, RoleAssignment, Role, ErrorCode, seeds};
+
+#[derive(Accounts)]
+pub struct SetMarketActive<'info> {
+    #[account(
+        seeds = [seeds::GAME_CONFIG],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [seeds::MARKET, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    /// MarketManager role of the signer (not needed for the config authority)
+    #[account(
+        seeds = [seeds::ROLE, Role::MarketManager.seed(), signer.key().as_ref()],
+        bump = role_assignment.bump
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    pub signer: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetMarketActive>, active: bool) -> Result<()> {
+    require!(
+        ctx.accounts.config.has_role(
+            &ctx.accounts.signer.key(),
+            Role::MarketManager,
+            ctx.accounts.role_assignment.as_deref()
+        ),
+        ErrorCode::Unauthorized
+    );
+
+    let market = &mut ctx.accounts.market;
+    market.active = active;
+
+    emit!(MarketStatusUpdated {
+        market_id: market.market_id,
+        active,
+        updated_by: ctx.accounts.signer.key(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct MarketStatusUpdated {
+    pub market_id: u64,
+    pub active: bool,
+    pub updated_by: Pubkey,
+}
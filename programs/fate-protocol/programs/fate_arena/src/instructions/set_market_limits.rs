@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use crate::{
+    GameConfig, Market, MarketLimits, MatchType, RoleAssignment, Role, ErrorCode, seeds,
+    constants::*, utils::limits::within
+};
+
+#[derive(Accounts)]
+pub struct SetMarketLimits<'info> {
+    #[account(
+        seeds = [seeds::GAME_CONFIG],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [seeds::MARKET, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    /// MarketManager role of the signer (not needed for the config authority)
+    #[account(
+        seeds = [seeds::ROLE, Role::MarketManager.seed(), signer.key().as_ref()],
+        bump = role_assignment.bump
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    pub signer: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetMarketLimits>, limits: MarketLimits) -> Result<()> {
+    require!(
+        ctx.accounts.config.has_role(
+            &ctx.accounts.signer.key(),
+            Role::MarketManager,
+            ctx.accounts.role_assignment.as_deref()
+        ),
+        ErrorCode::Unauthorized
+    );
+
+    let market = &mut ctx.accounts.market;
+    let token_market = market.accepted_mint.is_some();
+
+    // Overrides may only tighten the protocol-wide limits
+    if let Some(fee_bps) = limits.protocol_fee_bps {
+        require!(
+            fee_bps <= GameConfig::MAX_PROTOCOL_FEE_BPS,
+            ErrorCode::InvalidProtocolFee
+        );
+    }
+    require!(
+        within(
+            &limits.entry_fee_range(token_market),
+            &MarketLimits::default().entry_fee_range(token_market)
+        ),
+        ErrorCode::InvalidMarketLimits
+    );
+    require!(
+        within(
+            &limits.prediction_window_range(),
+            &(MIN_PREDICTION_WINDOW..=MAX_PREDICTION_WINDOW)
+        ),
+        ErrorCode::InvalidMarketLimits
+    );
+    require!(
+        within(
+            &limits.match_duration_range(),
+            &(MIN_MATCH_DURATION..=MAX_MATCH_DURATION)
+        ),
+        ErrorCode::InvalidMarketLimits
+    );
+    require!(
+        limits.allowed_match_types < 1 << (MatchType::Series as u8 + 1),
+        ErrorCode::InvalidMarketLimits
+    );
+
+    market.limits = limits;
+
+    emit!(MarketLimitsUpdated {
+        market_id: market.market_id,
+        limits,
+        updated_by: ctx.accounts.signer.key(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct MarketLimitsUpdated {
+    pub market_id: u64,
+    pub limits: MarketLimits,
+    pub updated_by: Pubkey,
+}
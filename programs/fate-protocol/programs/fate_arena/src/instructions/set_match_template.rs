@@ -0,0 +1,162 @@
+use anchor_lang::prelude::*;
+use crate::{
+    GameConfig, Market, MatchTemplate, MatchType, RoleAssignment, Role, ErrorCode, seeds,
+    constants::*
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetMatchTemplateParams {
+    /// Template slot (an index equal to the current count appends a new template)
+    pub index: u8,
+    pub match_type: MatchType,
+    pub entry_fee: u64,
+    pub max_players: u8,
+    pub prediction_window: i64,
+    pub match_duration: i64,
+    /// Seconds between spawns
+    pub cadence: i64,
+    /// Lamports seeded into each match from the market's house funds (0 = none)
+    pub house_bonus: u64,
+    /// Pause or resume the schedule
+    pub active: bool,
+}
+
+#[derive(Accounts)]
+pub struct SetMatchTemplate<'info> {
+    #[account(
+        seeds = [seeds::GAME_CONFIG],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [seeds::MARKET, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    /// MarketManager role of the signer (not needed for the config authority)
+    #[account(
+        seeds = [seeds::ROLE, Role::MarketManager.seed(), signer.key().as_ref()],
+        bump = role_assignment.bump
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    pub signer: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetMatchTemplate>, params: SetMatchTemplateParams) -> Result<()> {
+    require!(
+        ctx.accounts.config.has_role(
+            &ctx.accounts.signer.key(),
+            Role::MarketManager,
+            ctx.accounts.role_assignment.as_deref()
+        ),
+        ErrorCode::Unauthorized
+    );
+
+    let market = &mut ctx.accounts.market;
+
+    // House bonuses are lamports, so templates only run on SOL markets
+    require!(market.accepted_mint.is_none(), ErrorCode::InvalidMint);
+
+    // Same bounds as a hand-created match on this market
+    let limits = &market.limits;
+    require!(
+        limits.entry_fee_range(false).contains(&params.entry_fee),
+        ErrorCode::InvalidEntryFee
+    );
+    require!(
+        params.max_players >= 2 && params.max_players <= MAX_PLAYERS,
+        ErrorCode::InvalidMaxPlayers
+    );
+    require!(
+        limits.prediction_window_range().contains(&params.prediction_window),
+        ErrorCode::InvalidPredictionWindow
+    );
+    require!(
+        limits.match_duration_range().contains(&params.match_duration),
+        ErrorCode::InvalidPredictionWindow
+    );
+    require!(
+        limits.allows(params.match_type),
+        ErrorCode::MatchTypeNotAllowed
+    );
+    require!(
+        !matches!(params.match_type, MatchType::Tournament | MatchType::Series),
+        ErrorCode::InvalidMatchType
+    );
+    if params.match_type == MatchType::BattleRoyale {
+        require!(
+            params.max_players >= MIN_BATTLE_ROYALE_PLAYERS,
+            ErrorCode::InvalidMaxPlayers
+        );
+    }
+    require!(
+        params.cadence >= MIN_TEMPLATE_CADENCE,
+        ErrorCode::InvalidMatchTemplate
+    );
+    // A bonus must leave a fresh vault rent-exempt
+    require!(
+        params.house_bonus == 0 || params.house_bonus >= MIN_ENTRY_FEE,
+        ErrorCode::InvalidMatchTemplate
+    );
+
+    let index = params.index as usize;
+    require!(
+        index <= market.templates.len() && index < MAX_MARKET_TEMPLATES,
+        ErrorCode::InvalidMatchTemplate
+    );
+
+    // A new template spawns right away; an edited one keeps its schedule
+    let next_spawn_at = match market.templates.get(index) {
+        Some(existing) => existing.next_spawn_at,
+        None => Clock::get()?.unix_timestamp,
+    };
+
+    let template = MatchTemplate {
+        match_type: params.match_type,
+        entry_fee: params.entry_fee,
+        max_players: params.max_players,
+        prediction_window: params.prediction_window,
+        match_duration: params.match_duration,
+        cadence: params.cadence,
+        house_bonus: params.house_bonus,
+        next_spawn_at,
+        active: params.active,
+    };
+
+    if index == market.templates.len() {
+        market.templates.push(template);
+    } else {
+        market.templates[index] = template;
+    }
+
+    emit!(MatchTemplateSet {
+        market_id: market.market_id,
+        index: params.index,
+        match_type: params.match_type,
+        entry_fee: params.entry_fee,
+        cadence: params.cadence,
+        house_bonus: params.house_bonus,
+        next_spawn_at,
+        active: params.active,
+        updated_by: ctx.accounts.signer.key(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct MatchTemplateSet {
+    pub market_id: u64,
+    pub index: u8,
+    pub match_type: MatchType,
+    pub entry_fee: u64,
+    pub cadence: i64,
+    pub house_bonus: u64,
+    pub next_spawn_at: i64,
+    pub active: bool,
+    pub updated_by: Pubkey,
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::{GameConfig, RoleAssignment, Role, ErrorCode, seeds};
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    #[account(
+        mut,
+        seeds = [seeds::GAME_CONFIG],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    /// Guardian role of the signer (not needed for the config authority)
+    #[account(
+        seeds = [seeds::ROLE, Role::Guardian.seed(), signer.key().as_ref()],
+        bump = role_assignment.bump
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    pub signer: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let signer = ctx.accounts.signer.key();
+
+    // Guardians can stop the game; only the authority can restart it
+    if paused {
+        require!(
+            config.has_role(&signer, Role::Guardian, ctx.accounts.role_assignment.as_deref()),
+            ErrorCode::Unauthorized
+        );
+    } else {
+        require_keys_eq!(signer, config.authority, ErrorCode::Unauthorized);
+    }
+
+    config.paused = paused;
+
+    emit!(PauseUpdated {
+        paused,
+        updated_by: signer,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct PauseUpdated {
+    pub paused: bool,
+    pub updated_by: Pubkey,
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::{Referrer, UserProfile, ErrorCode, seeds};
+
+#[derive(Accounts)]
+pub struct SetReferrer<'info> {
+    #[account(
+        mut,
+        seeds = [seeds::USER_PROFILE, player.key().as_ref()],
+        bump = user_profile.bump,
+        constraint = user_profile.referrer.is_none() @ ErrorCode::ReferrerAlreadySet
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut,
+        seeds = [seeds::REFERRER, referrer_account.referrer.as_ref()],
+        bump = referrer_account.bump,
+        constraint = referrer_account.referrer != player.key() @ ErrorCode::SelfReferral
+    )]
+    pub referrer_account: Account<'info, Referrer>,
+
+    pub player: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetReferrer>) -> Result<()> {
+    let user_profile = &mut ctx.accounts.user_profile;
+    let referrer_account = &mut ctx.accounts.referrer_account;
+
+    user_profile.referrer = Some(referrer_account.referrer);
+    referrer_account.referred_count = referrer_account.referred_count.checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(ReferrerSet {
+        player: ctx.accounts.player.key(),
+        referrer: referrer_account.referrer,
+        referred_count: referrer_account.referred_count,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ReferrerSet {
+    pub player: Pubkey,
+    pub referrer: Pubkey,
+    pub referred_count: u64,
+}
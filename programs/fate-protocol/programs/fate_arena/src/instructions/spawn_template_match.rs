@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use crate::{
+    GameConfig, Market, Match, ErrorCode, seeds,
+    utils::schedule::next_spawn_slot
+};
+
+/// Permissionless crank: opens the next scheduled match of a market template
+/// The payer covers rent for the match; players join it as usual
+#[derive(Accounts)]
+pub struct SpawnTemplateMatch<'info> {
+    #[account(
+        mut,
+        seeds = [seeds::GAME_CONFIG],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::GamePaused
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [seeds::MARKET, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.active @ ErrorCode::MarketNotActive
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Match::LEN,
+        seeds = [seeds::MATCH, config.total_matches.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub match_account: Account<'info, Match>,
+
+    /// CHECK: Match escrow vault
+    #[account(
+        mut,
+        seeds = [seeds::VAULT, match_account.key().as_ref()],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SpawnTemplateMatch>, index: u8) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let market = &mut ctx.accounts.market;
+    let match_account = &mut ctx.accounts.match_account;
+    let clock = Clock::get()?;
+
+    let template = *market.templates.get(index as usize)
+        .ok_or(ErrorCode::InvalidMatchTemplate)?;
+    require!(template.active, ErrorCode::InvalidMatchTemplate);
+    require!(
+        clock.unix_timestamp >= template.next_spawn_at,
+        ErrorCode::TemplateNotDue
+    );
+
+    // House bonus comes from lamports deposited on the market account above
+    // its rent-exempt minimum; skip it rather than fail when underfunded
+    let market_info = market.to_account_info();
+    let available = market_info.lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(market_info.data_len()));
+    let house_bonus = if available >= template.house_bonus {
+        template.house_bonus
+    } else {
+        0
+    };
+
+    if house_bonus > 0 {
+        **market_info.try_borrow_mut_lamports()? -= house_bonus;
+        **ctx.accounts.vault.try_borrow_mut_lamports()? += house_bonus;
+    }
+
+    let match_id = config.total_matches;
+
+    // The market opens the lobby; nobody has joined yet
+    match_account.set_inner(Match {
+        entry_fee: template.entry_fee,
+        max_players: template.max_players,
+        prediction_window: template.prediction_window,
+        resolution_time: clock.unix_timestamp + template.prediction_window + template.match_duration,
+        protocol_fee_bps: market.limits.protocol_fee_bps,
+        house_bonus,
+        ..Match::new(
+            match_id,
+            market.key(),
+            market.key(),
+            template.match_type,
+            ctx.bumps.match_account,
+            clock.unix_timestamp,
+        )
+    });
+
+    let next_spawn_at = next_spawn_slot(template.next_spawn_at, template.cadence, clock.unix_timestamp);
+    market.templates[index as usize].next_spawn_at = next_spawn_at;
+
+    // Update counters
+    config.total_matches = config.total_matches.checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    market.total_matches = market.total_matches.checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(TemplateMatchSpawned {
+        market_id: market.market_id,
+        template_index: index,
+        match_id,
+        house_bonus,
+        next_spawn_at,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct TemplateMatchSpawned {
+    pub market_id: u64,
+    pub template_index: u8,
+    pub match_id: u64,
+    pub house_bonus: u64,
+    pub next_spawn_at: i64,
+}
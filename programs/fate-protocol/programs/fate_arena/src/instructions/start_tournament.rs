@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::{
+    Tournament, TournamentStatus, ErrorCode, seeds, utils::bracket::seeding_order
+};
+
+/// Permissionless once registration is full
+#[derive(Accounts)]
+pub struct StartTournament<'info> {
+    #[account(
+        mut,
+        seeds = [seeds::TOURNAMENT, tournament.tournament_id.to_le_bytes().as_ref()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::Registration @ ErrorCode::InvalidTournamentStatus,
+        constraint = tournament.is_full() @ ErrorCode::InvalidTournamentStatus
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    pub caller: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<StartTournament>) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let clock = Clock::get()?;
+
+    // Seed by level (highest first), registration order breaks ties
+    let mut by_seed: Vec<u8> = (0..tournament.players.len() as u8).collect();
+    by_seed.sort_by(|&a, &b| {
+        tournament.seed_levels[b as usize]
+            .cmp(&tournament.seed_levels[a as usize])
+            .then(a.cmp(&b))
+    });
+
+    // Place seeds so the top seeds meet last
+    tournament.bracket = seeding_order(by_seed.len())
+        .into_iter()
+        .map(|seed| by_seed[seed])
+        .collect();
+
+    tournament.status = TournamentStatus::InProgress;
+    tournament.current_round = 1;
+    tournament.started_at = Some(clock.unix_timestamp);
+
+    emit!(TournamentStarted {
+        tournament_id: tournament.tournament_id,
+        bracket: tournament.bracket.iter().map(|&i| tournament.players[i as usize]).collect(),
+        total_rounds: tournament.total_rounds,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct TournamentStarted {
+    pub tournament_id: u64,
+    /// Players in bracket order (slot 2k plays slot 2k+1)
+    pub bracket: Vec<Pubkey>,
+    pub total_rounds: u8,
+}
@@ -43,6 +43,16 @@ pub struct SubmitPrediction<'info> {
     pub price_update: AccountInfo<'info>,
 
     pub player: Signer<'info>,
+
+    /// Second market (relative-performance matches only)
+    #[account(
+        seeds = [seeds::MARKET, market_b.market_id.to_le_bytes().as_ref()],
+        bump = market_b.bump
+    )]
+    pub market_b: Option<Account<'info, Market>>,
+
+    /// CHECK: Pyth price account of market_b - key checked in get_second_market_price()
+    pub price_update_b: Option<UncheckedAccount<'info>>,
 }
 
 pub fn handler(ctx: Context<SubmitPrediction>, params: SubmitPredictionParams) -> Result<()> {
@@ -58,6 +68,14 @@ pub fn handler(ctx: Context<SubmitPrediction>, params: SubmitPredictionParams) -
         ErrorCode::PredictionWindowNotClosed
     );
 
+    require!(!match_account.sealed, ErrorCode::SealedPredictionRequired);
+
+    // Range matches take a bucket, closest-price matches a target, others Higher/Lower
+    require!(
+        match_account.accepts_prediction(params.prediction),
+        ErrorCode::InvalidPrediction
+    );
+
     // Get feed ID from market
     let feed_id_hex = market.pyth_price_feed.to_string();
 
@@ -88,6 +106,19 @@ pub fn handler(ctx: Context<SubmitPrediction>, params: SubmitPredictionParams) -
             ErrorCode::ConfidenceIntervalTooWide
         );
 
+        // Relative matches capture the second market at the same moment
+        if let Some(market_b) = match_account.market_b {
+            let price_update_b = ctx.accounts.price_update_b.as_ref().map(|a| a.to_account_info());
+            let start_price_b = get_second_market_price(
+                &market_b,
+                ctx.accounts.market_b.as_ref(),
+                price_update_b.as_ref(),
+                &clock,
+            )?;
+            msg!("Second market start price set: {}", start_price_b);
+            match_account.start_price_b = Some(start_price_b);
+        }
+
         // Record start price and start the match
         match_account.start_price = Some(pyth_price.normalized_price);
         match_account.status = MatchStatus::InProgress;
@@ -95,6 +126,7 @@ pub fn handler(ctx: Context<SubmitPrediction>, params: SubmitPredictionParams) -
     }
 
     // Record player's prediction
+    match_account.record_prediction(params.prediction)?;
     player_entry.prediction = Some(params.prediction);
     player_entry.prediction_locked_at = Some(clock.unix_timestamp);
 
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use crate::{
+    Market, Match, PlayerEntry, EliminationRound, MatchStatus, PredictionSide,
+    ErrorCode, seeds, utils::pyth::*,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SubmitRoundPredictionParams {
+    /// Higher or Lower for the current round
+    pub prediction: PredictionSide,
+}
+
+#[derive(Accounts)]
+pub struct SubmitRoundPrediction<'info> {
+    #[account(
+        seeds = [seeds::MARKET, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        seeds = [seeds::MATCH, match_account.match_id.to_le_bytes().as_ref()],
+        bump = match_account.bump,
+        has_one = market,
+        constraint = match_account.is_elimination_mode() @ ErrorCode::InvalidMatchType,
+        constraint = match_account.status == MatchStatus::Open ||
+                     match_account.status == MatchStatus::InProgress @ ErrorCode::InvalidMatchStatus
+    )]
+    pub match_account: Account<'info, Match>,
+
+    #[account(
+        mut,
+        seeds = [
+            seeds::PLAYER_ENTRY,
+            match_account.key().as_ref(),
+            player.key().as_ref()
+        ],
+        bump = player_entry.bump
+    )]
+    pub player_entry: Account<'info, PlayerEntry>,
+
+    /// CHECK: Pyth price update account - validated in handler via validate_price_feed() and get_pyth_price()
+    pub price_update: AccountInfo<'info>,
+
+    pub player: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SubmitRoundPrediction>, params: SubmitRoundPredictionParams) -> Result<()> {
+    let match_account = &mut ctx.accounts.match_account;
+    let player_entry = &mut ctx.accounts.player_entry;
+    let market = &ctx.accounts.market;
+    let clock = Clock::get()?;
+
+    // Check if within this round's prediction window
+    let prediction_deadline = match_account.round_started_at + match_account.prediction_window;
+    require!(
+        clock.unix_timestamp <= prediction_deadline,
+        ErrorCode::PredictionWindowNotClosed
+    );
+
+    let index = player_entry.player_index;
+    let bit = 1u16 << index;
+    require!(match_account.is_survivor(index), ErrorCode::PlayerEliminated);
+    require!(
+        (match_account.higher_mask | match_account.lower_mask) & bit == 0,
+        ErrorCode::PredictionAlreadyLocked
+    );
+
+    // Later rounds start from the previous round's end price; the first
+    // prediction of round one reads the oracle and starts the match
+    if match_account.rounds.is_empty() {
+        let feed_id_hex = market.pyth_price_feed.to_string();
+        validate_price_feed(&ctx.accounts.price_update.to_account_info(), &feed_id_hex)?;
+
+        let pyth_price = get_pyth_price(
+            &ctx.accounts.price_update,
+            &feed_id_hex,
+            &clock,
+        )?;
+
+        require!(
+            pyth_price.is_confidence_acceptable(),
+            ErrorCode::ConfidenceIntervalTooWide
+        );
+
+        msg!("Round 1 start price set: {}", pyth_price.normalized_price);
+
+        match_account.rounds.push(EliminationRound {
+            start_price: pyth_price.normalized_price,
+            end_price: None,
+            winning_side: None,
+            survivors: 0,
+        });
+        match_account.start_price = Some(pyth_price.normalized_price);
+        match_account.status = MatchStatus::InProgress;
+        match_account.started_at = Some(clock.unix_timestamp);
+    }
+
+    match params.prediction {
+        PredictionSide::Higher => match_account.higher_mask |= bit,
+        PredictionSide::Lower => match_account.lower_mask |= bit,
+        _ => return err!(ErrorCode::InvalidPrediction),
+    }
+
+    player_entry.prediction = Some(params.prediction);
+    player_entry.prediction_locked_at = Some(clock.unix_timestamp);
+
+    emit!(RoundPredictionSubmitted {
+        match_id: match_account.match_id,
+        round: match_account.current_round,
+        player: ctx.accounts.player.key(),
+        prediction: params.prediction,
+        locked_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RoundPredictionSubmitted {
+    pub match_id: u64,
+    pub round: u8,
+    pub player: Pubkey,
+    pub prediction: PredictionSide,
+    pub locked_at: i64,
+}
@@ -41,6 +41,7 @@ pub fn handler(ctx: Context<UpdateUserProfile>, params: UpdateUserProfileParams)
         user_profile.level = 1;
         user_profile.created_at = clock.unix_timestamp;
         user_profile.bump = ctx.bumps.user_profile;
+        user_profile.version = UserProfile::VERSION;
     }
 
     // Update username if provided
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::{GameConfig, QueuedAction, RoleAssignment, ConfigAction, Role, ErrorCode, seeds};
+
+#[derive(Accounts)]
+pub struct VetoConfigChange<'info> {
+    #[account(
+        seeds = [seeds::GAME_CONFIG],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [seeds::QUEUED_ACTION, queued_action.action_id.to_le_bytes().as_ref()],
+        bump = queued_action.bump,
+        has_one = proposer
+    )]
+    pub queued_action: Account<'info, QueuedAction>,
+
+    /// CHECK: Receives the queued action rent; validated by has_one
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+
+    /// Guardian role of the signer (not needed for the config authority)
+    #[account(
+        seeds = [seeds::ROLE, Role::Guardian.seed(), guardian.key().as_ref()],
+        bump = role_assignment.bump
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    pub guardian: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<VetoConfigChange>) -> Result<()> {
+    let guardian = ctx.accounts.guardian.key();
+
+    require!(
+        ctx.accounts.config.has_role(
+            &guardian,
+            Role::Guardian,
+            ctx.accounts.role_assignment.as_deref()
+        ),
+        ErrorCode::Unauthorized
+    );
+
+    let queued_action = &ctx.accounts.queued_action;
+
+    emit!(ConfigChangeVetoed {
+        action_id: queued_action.action_id,
+        action: queued_action.action,
+        vetoed_by: guardian,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ConfigChangeVetoed {
+    pub action_id: u64,
+    pub action: ConfigAction,
+    pub vetoed_by: Pubkey,
+}
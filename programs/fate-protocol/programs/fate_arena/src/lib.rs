@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use std::ops::RangeInclusive;
 
 pub mod instructions;
 pub mod utils;
@@ -29,16 +30,24 @@ pub mod fate_arena {
     }
 
     /// Create a new match (creator auto-joins)
-    pub fn create_match(
-        ctx: Context<CreateMatch>,
+    pub fn create_match<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateMatch<'info>>,
         params: CreateMatchParams,
     ) -> Result<()> {
         instructions::create_match::handler(ctx, params)
     }
 
     /// Join an existing match
-    pub fn join_match(ctx: Context<JoinMatch>) -> Result<()> {
-        instructions::join_match::handler(ctx)
+    pub fn join_match<'info>(
+        ctx: Context<'_, '_, '_, 'info, JoinMatch<'info>>,
+        params: JoinMatchParams,
+    ) -> Result<()> {
+        instructions::join_match::handler(ctx, params)
+    }
+
+    /// Leave an open match before predicting (non-creators only)
+    pub fn leave_match<'info>(ctx: Context<'_, '_, '_, 'info, LeaveMatch<'info>>) -> Result<()> {
+        instructions::leave_match::handler(ctx)
     }
 
     /// Submit prediction for a match
@@ -49,16 +58,119 @@ pub mod fate_arena {
         instructions::submit_prediction::handler(ctx, params)
     }
 
+    /// Commit a sealed prediction hash (sealed matches)
+    pub fn commit_prediction(
+        ctx: Context<CommitPrediction>,
+        params: CommitPredictionParams,
+    ) -> Result<()> {
+        instructions::commit_prediction::handler(ctx, params)
+    }
+
+    /// Reveal a sealed prediction after the prediction window
+    pub fn reveal_prediction(
+        ctx: Context<RevealPrediction>,
+        params: RevealPredictionParams,
+    ) -> Result<()> {
+        instructions::reveal_prediction::handler(ctx, params)
+    }
+
     /// Resolve a match using Pyth oracle
     pub fn resolve_match(ctx: Context<ResolveMatch>) -> Result<()> {
         instructions::resolve_match::handler(ctx)
     }
 
+    /// Predict the current round of an elimination match
+    pub fn submit_round_prediction(
+        ctx: Context<SubmitRoundPrediction>,
+        params: SubmitRoundPredictionParams,
+    ) -> Result<()> {
+        instructions::submit_round_prediction::handler(ctx, params)
+    }
+
+    /// Resolve the current round of an elimination match (anyone)
+    pub fn resolve_round(ctx: Context<ResolveRound>) -> Result<()> {
+        instructions::resolve_round::handler(ctx)
+    }
+
     /// Claim winnings from a completed match
-    pub fn claim_winnings(ctx: Context<ClaimWinnings>) -> Result<()> {
+    pub fn claim_winnings<'info>(ctx: Context<'_, '_, '_, 'info, ClaimWinnings<'info>>) -> Result<()> {
         instructions::claim_winnings::handler(ctx)
     }
 
+    /// Grant a role to a wallet (authority only)
+    pub fn grant_role(
+        ctx: Context<GrantRole>,
+        params: GrantRoleParams,
+    ) -> Result<()> {
+        instructions::grant_role::handler(ctx, params)
+    }
+
+    /// Revoke a role from a wallet (authority only)
+    pub fn revoke_role(ctx: Context<RevokeRole>) -> Result<()> {
+        instructions::revoke_role::handler(ctx)
+    }
+
+    /// Pause or unpause the game (guardian can only pause)
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        instructions::set_paused::handler(ctx, paused)
+    }
+
+    /// Queue a fee, treasury or delay change behind the timelock
+    pub fn queue_config_change(
+        ctx: Context<QueueConfigChange>,
+        params: QueueConfigChangeParams,
+    ) -> Result<()> {
+        instructions::queue_config_change::handler(ctx, params)
+    }
+
+    /// Apply a queued config change once its ETA has passed (anyone)
+    pub fn execute_config_change(ctx: Context<ExecuteConfigChange>) -> Result<()> {
+        instructions::execute_config_change::handler(ctx)
+    }
+
+    /// Veto a queued config change (guardian or authority)
+    pub fn veto_config_change(ctx: Context<VetoConfigChange>) -> Result<()> {
+        instructions::veto_config_change::handler(ctx)
+    }
+
+    /// Enable or disable a market for new matches
+    pub fn set_market_active(ctx: Context<SetMarketActive>, active: bool) -> Result<()> {
+        instructions::set_market_active::handler(ctx, active)
+    }
+
+    /// Add or update a recurring match template on a market (authority or market manager)
+    pub fn set_match_template(
+        ctx: Context<SetMatchTemplate>,
+        params: SetMatchTemplateParams,
+    ) -> Result<()> {
+        instructions::set_match_template::handler(ctx, params)
+    }
+
+    /// Set a market's fee and match limit overrides (authority or market manager)
+    pub fn set_market_limits(ctx: Context<SetMarketLimits>, limits: MarketLimits) -> Result<()> {
+        instructions::set_market_limits::handler(ctx, limits)
+    }
+
+    /// Open the next scheduled match of a market template (anyone)
+    pub fn spawn_template_match(ctx: Context<SpawnTemplateMatch>, index: u8) -> Result<()> {
+        instructions::spawn_template_match::handler(ctx, index)
+    }
+
+    /// Return an unused house bonus from a cancelled or refunded match (anyone)
+    pub fn reclaim_house_bonus(ctx: Context<ReclaimHouseBonus>) -> Result<()> {
+        instructions::reclaim_house_bonus::handler(ctx)
+    }
+
+    /// Expire an Open match that never filled (anyone, after lobby timeout)
+    pub fn expire_match(ctx: Context<ExpireMatch>) -> Result<()> {
+        instructions::expire_match::handler(ctx)
+    }
+
+    /// Refund entry fee from a cancelled match
+    pub fn claim_refund<'info>(ctx: Context<'_, '_, '_, 'info, ClaimRefund<'info>>) -> Result<()> {
+        instructions::claim_refund::handler(ctx)
+    }
+
     /// Cancel an open match
     pub fn cancel_match(ctx: Context<CancelMatch>) -> Result<()> {
         instructions::cancel_match::handler(ctx)
@@ -71,6 +183,157 @@ pub mod fate_arena {
     ) -> Result<()> {
         instructions::update_user_profile::handler(ctx, params)
     }
+
+    /// Create a single-elimination tournament
+    pub fn create_tournament(
+        ctx: Context<CreateTournament>,
+        params: CreateTournamentParams,
+    ) -> Result<()> {
+        instructions::create_tournament::handler(ctx, params)
+    }
+
+    /// Register for a tournament (pays entry fee)
+    pub fn register_tournament(ctx: Context<RegisterTournament>) -> Result<()> {
+        instructions::register_tournament::handler(ctx)
+    }
+
+    /// Seed the bracket once registration is full (anyone)
+    pub fn start_tournament(ctx: Context<StartTournament>) -> Result<()> {
+        instructions::start_tournament::handler(ctx)
+    }
+
+    /// Create the next bracket match of the current round (anyone)
+    pub fn create_bracket_match(ctx: Context<CreateBracketMatch>) -> Result<()> {
+        instructions::create_bracket_match::handler(ctx)
+    }
+
+    /// Decide the current round and advance winners (anyone)
+    pub fn advance_tournament(ctx: Context<AdvanceTournament>) -> Result<()> {
+        instructions::advance_tournament::handler(ctx)
+    }
+
+    /// Cancel a tournament during registration
+    pub fn cancel_tournament(ctx: Context<CancelTournament>) -> Result<()> {
+        instructions::cancel_tournament::handler(ctx)
+    }
+
+    /// Claim a placement prize or a refund from a cancelled tournament
+    pub fn claim_tournament_prize(ctx: Context<ClaimTournamentPrize>) -> Result<()> {
+        instructions::claim_tournament_prize::handler(ctx)
+    }
+
+    /// Create a best-of-N series (pays the creator's stake)
+    pub fn create_series(
+        ctx: Context<CreateSeries>,
+        params: CreateSeriesParams,
+    ) -> Result<()> {
+        instructions::create_series::handler(ctx, params)
+    }
+
+    /// Join an open series (pays the matching stake)
+    pub fn join_series(ctx: Context<JoinSeries>) -> Result<()> {
+        instructions::join_series::handler(ctx)
+    }
+
+    /// Create the next round match of a series (anyone)
+    pub fn create_series_match(ctx: Context<CreateSeriesMatch>) -> Result<()> {
+        instructions::create_series_match::handler(ctx)
+    }
+
+    /// Score the round in play and settle the series once decided (anyone)
+    pub fn record_series_result(ctx: Context<RecordSeriesResult>) -> Result<()> {
+        instructions::record_series_result::handler(ctx)
+    }
+
+    /// Cancel a series before an opponent joins
+    pub fn cancel_series(ctx: Context<CancelSeries>) -> Result<()> {
+        instructions::cancel_series::handler(ctx)
+    }
+
+    /// Claim the series prize or a refund from a cancelled series
+    pub fn claim_series_prize(ctx: Context<ClaimSeriesPrize>) -> Result<()> {
+        instructions::claim_series_prize::handler(ctx)
+    }
+
+    /// Create the protocol fee vault (authority only)
+    pub fn initialize_fee_vault(ctx: Context<InitializeFeeVault>) -> Result<()> {
+        instructions::initialize_fee_vault::handler(ctx)
+    }
+
+    /// Split the fee vault's balance between the fee recipients and the treasury (anyone)
+    pub fn distribute_fees<'info>(
+        ctx: Context<'_, '_, '_, 'info, DistributeFees<'info>>,
+    ) -> Result<()> {
+        instructions::distribute_fees::handler(ctx)
+    }
+
+    /// Open a referral balance for the signer
+    pub fn register_referrer(ctx: Context<RegisterReferrer>) -> Result<()> {
+        instructions::register_referrer::handler(ctx)
+    }
+
+    /// Record the wallet that referred the signer (once per profile)
+    pub fn set_referrer(ctx: Context<SetReferrer>) -> Result<()> {
+        instructions::set_referrer::handler(ctx)
+    }
+
+    /// Withdraw accrued referral fees
+    pub fn claim_referral_fees(ctx: Context<ClaimReferralFees>) -> Result<()> {
+        instructions::claim_referral_fees::handler(ctx)
+    }
+
+    /// Create the protocol insurance fund (authority only)
+    pub fn initialize_insurance_fund(ctx: Context<InitializeInsuranceFund>) -> Result<()> {
+        instructions::initialize_insurance_fund::handler(ctx)
+    }
+
+    /// Add lamports to the insurance fund (anyone)
+    pub fn deposit_insurance(ctx: Context<DepositInsurance>, amount: u64) -> Result<()> {
+        instructions::deposit_insurance::handler(ctx, amount)
+    }
+
+    /// Top up a match vault that cannot pay its claims (authority or InsuranceManager)
+    pub fn cover_shortfall(
+        ctx: Context<CoverShortfall>,
+        params: CoverShortfallParams,
+    ) -> Result<()> {
+        instructions::cover_shortfall::handler(ctx, params)
+    }
+
+    /// Create the progressive jackpot (authority only)
+    pub fn initialize_jackpot(ctx: Context<InitializeJackpot>) -> Result<()> {
+        instructions::initialize_jackpot::handler(ctx)
+    }
+
+    /// Withdraw a jackpot award won when claiming a match
+    pub fn claim_jackpot(ctx: Context<ClaimJackpot>) -> Result<()> {
+        instructions::claim_jackpot::handler(ctx)
+    }
+
+    /// Upgrade a GameConfig account to the current layout
+    pub fn migrate_config(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<GameConfig>(ctx)
+    }
+
+    /// Upgrade a Market account to the current layout
+    pub fn migrate_market(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<Market>(ctx)
+    }
+
+    /// Upgrade a Match account to the current layout
+    pub fn migrate_match(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<Match>(ctx)
+    }
+
+    /// Upgrade a PlayerEntry account to the current layout
+    pub fn migrate_player_entry(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<PlayerEntry>(ctx)
+    }
+
+    /// Upgrade a UserProfile account to the current layout
+    pub fn migrate_user_profile(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<UserProfile>(ctx)
+    }
 }
 
 // ============================================================================
@@ -101,6 +364,46 @@ pub struct GameConfig {
 
     /// PDA bump
     pub bump: u8,
+
+    /// Account layout version
+    pub version: u8,
+
+    /// Delay in seconds before a queued config change can execute
+    pub admin_delay: i64,
+
+    /// Total number of config changes queued
+    pub total_queued_actions: u64,
+
+    /// Seconds an Open match may wait for players before anyone can expire it
+    pub lobby_timeout: i64,
+
+    /// Lamports paid from the config account to whoever expires a stale match
+    pub keeper_reward: u64,
+
+    /// Total number of tournaments created
+    pub total_tournaments: u64,
+
+    /// Total number of series created
+    pub total_series: u64,
+
+    /// SOL/USD price account used to convert USD-pegged entry fees
+    /// (default pubkey = USD pricing disabled)
+    pub sol_usd_feed: Pubkey,
+
+    /// Fee discounts by player level or rolling volume (unused tiers are zeroed)
+    pub fee_tiers: [FeeTier; constants::MAX_FEE_TIERS],
+
+    /// Share of a referred player's protocol fee paid to their referrer, in bps
+    pub referral_share_bps: u16,
+
+    /// Highest creator fee a match may set, in bps of the prize pool
+    pub max_creator_fee_bps: u16,
+
+    /// Share of the jackpot awarded per hit, in bps (0 = jackpot disabled)
+    pub jackpot_payout_bps: u16,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 6],
 }
 
 impl GameConfig {
@@ -111,10 +414,472 @@ impl GameConfig {
         8 +  // total_matches
         8 +  // total_volume
         1 +  // paused
-        1;   // bump
+        1 +  // bump
+        1 +  // version
+        8 +  // admin_delay
+        8 +  // total_queued_actions
+        8 +  // lobby_timeout
+        8 +  // keeper_reward
+        8 +  // total_tournaments
+        8 +  // total_series
+        32 + // sol_usd_feed
+        FeeTier::LEN * constants::MAX_FEE_TIERS + // fee_tiers
+        2 +  // referral_share_bps
+        2 +  // max_creator_fee_bps
+        2 +  // jackpot_payout_bps
+        6;   // reserved
+
+    /// Current account layout version
+    pub const VERSION: u8 = 1;
 
     /// Maximum protocol fee (10% = 1000 bps)
     pub const MAX_PROTOCOL_FEE_BPS: u16 = 1000;
+
+    /// Effective timelock for queued changes (never below the protocol minimum)
+    pub fn effective_admin_delay(&self) -> i64 {
+        self.admin_delay.max(constants::MIN_ADMIN_DELAY)
+    }
+
+    /// Effective lobby timeout (accounts created before the field existed use the default)
+    pub fn effective_lobby_timeout(&self) -> i64 {
+        if self.lobby_timeout > 0 {
+            self.lobby_timeout
+        } else {
+            constants::DEFAULT_LOBBY_TIMEOUT
+        }
+    }
+
+    /// Check if `signer` may act with `role`
+    /// The authority holds every role; anyone else needs a matching assignment
+    pub fn has_role(&self, signer: &Pubkey, role: Role, assignment: Option<&RoleAssignment>) -> bool {
+        *signer == self.authority ||
+            matches!(assignment, Some(a) if a.holder == *signer && a.role == role)
+    }
+}
+
+/// Single-elimination tournament played through child matches
+/// PDA: ["tournament", tournament_id.to_le_bytes()]
+#[account]
+pub struct Tournament {
+    /// Unique tournament identifier
+    pub tournament_id: u64,
+
+    /// Market every bracket match is played on
+    pub market: Pubkey,
+
+    /// Tournament creator
+    pub creator: Pubkey,
+
+    /// Entry fee in lamports
+    pub entry_fee: u64,
+
+    /// Bracket size (4, 8 or 16)
+    pub max_players: u8,
+
+    /// Tournament status
+    pub status: TournamentStatus,
+
+    /// Prediction window for each bracket match
+    pub prediction_window: i64,
+
+    /// Duration of each bracket match after the prediction window
+    pub match_duration: i64,
+
+    /// Registration closes at this timestamp
+    pub registration_ends_at: i64,
+
+    /// Prize split in bps: [champion, runner-up, each semifinal loser * 2]
+    pub payout_bps: [u16; 3],
+
+    /// Registered players in registration order
+    pub players: Vec<Pubkey>,
+
+    /// Player level at registration (used for seeding)
+    pub seed_levels: Vec<u16>,
+
+    /// Player indices in bracket order (set when the tournament starts)
+    pub bracket: Vec<u8>,
+
+    /// Round in which each player was eliminated (0 = still in)
+    pub eliminated_in: Vec<u8>,
+
+    /// Current round (1-based, 0 before start)
+    pub current_round: u8,
+
+    /// Number of rounds in the bracket
+    pub total_rounds: u8,
+
+    /// Match ids of the current round, one per pairing
+    pub round_match_ids: Vec<u64>,
+
+    /// Total entry fees collected
+    pub total_pot: u64,
+
+    /// Pot left for placements after the protocol fee (set on completion)
+    pub prize_pool: u64,
+
+    /// Bit per player index set once the prize or refund is claimed
+    pub claimed_mask: u16,
+
+    /// Creation timestamp
+    pub created_at: i64,
+
+    /// Start timestamp
+    pub started_at: Option<i64>,
+
+    /// Completion timestamp
+    pub completed_at: Option<i64>,
+
+    /// PDA bump
+    pub bump: u8,
+
+    /// Account layout version
+    pub version: u8,
+
+    /// Protocol fee override of the market at creation (None = config fee)
+    pub protocol_fee_bps: Option<u16>,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 61],
+}
+
+impl Tournament {
+    pub const LEN: usize = 8 +  // discriminator
+        8 +  // tournament_id
+        32 + // market
+        32 + // creator
+        8 +  // entry_fee
+        1 +  // max_players
+        1 +  // status (enum)
+        8 +  // prediction_window
+        8 +  // match_duration
+        8 +  // registration_ends_at
+        2 * 3 + // payout_bps
+        4 + 32 * constants::MAX_TOURNAMENT_PLAYERS + // players
+        4 + 2 * constants::MAX_TOURNAMENT_PLAYERS +  // seed_levels
+        4 + constants::MAX_TOURNAMENT_PLAYERS +      // bracket
+        4 + constants::MAX_TOURNAMENT_PLAYERS +      // eliminated_in
+        1 +  // current_round
+        1 +  // total_rounds
+        4 + 8 * constants::MAX_TOURNAMENT_PLAYERS / 2 + // round_match_ids
+        8 +  // total_pot
+        8 +  // prize_pool
+        2 +  // claimed_mask
+        8 +  // created_at
+        1 + 8 + // started_at (Option<i64>)
+        1 + 8 + // completed_at (Option<i64>)
+        1 +  // bump
+        1 +  // version
+        1 + 2 + // protocol_fee_bps (Option<u16>)
+        61;  // reserved
+
+    /// Current account layout version
+    pub const VERSION: u8 = 1;
+
+    /// Fee charged on the pot: its market's override, else the config fee
+    pub fn fee_bps(&self, config_fee_bps: u16) -> u16 {
+        self.protocol_fee_bps.unwrap_or(config_fee_bps)
+    }
+
+    /// Check if registration is full
+    pub fn is_full(&self) -> bool {
+        self.players.len() >= self.max_players as usize
+    }
+
+    /// Index of `player` in registration order
+    pub fn player_index(&self, player: &Pubkey) -> Option<usize> {
+        self.players.iter().position(|p| p == player)
+    }
+
+    /// Player indices still in, in bracket order
+    pub fn alive_in_bracket(&self) -> Vec<u8> {
+        self.bracket
+            .iter()
+            .copied()
+            .filter(|&i| self.eliminated_in[i as usize] == 0)
+            .collect()
+    }
+
+    /// Number of pairings in the current round
+    pub fn pairings_in_round(&self) -> usize {
+        self.alive_in_bracket().len() / 2
+    }
+
+    /// Seed rank of a player (position in `bracket` order is not the seed)
+    pub fn seed_of(&self, player_index: u8) -> u8 {
+        let positions = utils::bracket::seeding_order(self.bracket.len());
+        let slot = self.bracket.iter().position(|&i| i == player_index).unwrap_or(0);
+        positions[slot] as u8
+    }
+
+    /// Payout share in bps for a player once the tournament is completed
+    pub fn placement_bps(&self, player_index: usize) -> u16 {
+        match self.eliminated_in[player_index] {
+            0 => self.payout_bps[0],
+            round if round == self.total_rounds => self.payout_bps[1],
+            round if round + 1 == self.total_rounds => self.payout_bps[2] / 2,
+            _ => 0,
+        }
+    }
+
+    /// Check if a player has claimed their prize or refund
+    pub fn has_claimed(&self, player_index: usize) -> bool {
+        self.claimed_mask & (1 << player_index) != 0
+    }
+}
+
+/// Best-of-N series of back-to-back duels between two players
+/// PDA: ["series", series_id.to_le_bytes()]
+#[account]
+pub struct Series {
+    /// Unique series identifier
+    pub series_id: u64,
+
+    /// Market every round is played on
+    pub market: Pubkey,
+
+    /// Series creator
+    pub player_a: Pubkey,
+
+    /// Opponent (set when they join)
+    pub player_b: Option<Pubkey>,
+
+    /// Only this wallet may join (None = open challenge)
+    pub invited: Option<Pubkey>,
+
+    /// Stake per player in lamports (paid once for the whole series)
+    pub entry_fee: u64,
+
+    /// Number of rounds in the series (odd)
+    pub best_of: u8,
+
+    /// Rounds won by player A
+    pub wins_a: u8,
+
+    /// Rounds won by player B
+    pub wins_b: u8,
+
+    /// Round matches played, including replayed ones
+    pub matches_played: u8,
+
+    /// Series status
+    pub status: SeriesStatus,
+
+    /// Prediction window for each round
+    pub prediction_window: i64,
+
+    /// Duration of each round after the prediction window
+    pub match_duration: i64,
+
+    /// Match id of the round in play
+    pub current_match_id: Option<u64>,
+
+    /// Total stakes collected
+    pub total_pot: u64,
+
+    /// Pot left for the winner after the protocol fee (set on completion)
+    pub prize_pool: u64,
+
+    /// Series winner
+    pub winner: Option<Pubkey>,
+
+    /// Whether player A has claimed their prize or refund
+    pub claimed_a: bool,
+
+    /// Whether player B has claimed their prize or refund
+    pub claimed_b: bool,
+
+    /// Creation timestamp
+    pub created_at: i64,
+
+    /// Completion timestamp
+    pub completed_at: Option<i64>,
+
+    /// PDA bump
+    pub bump: u8,
+
+    /// Account layout version
+    pub version: u8,
+
+    /// Protocol fee override of the market at creation (None = config fee)
+    pub protocol_fee_bps: Option<u16>,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 61],
+}
+
+impl Series {
+    pub const LEN: usize = 8 +  // discriminator
+        8 +  // series_id
+        32 + // market
+        32 + // player_a
+        1 + 32 + // player_b (Option<Pubkey>)
+        1 + 32 + // invited (Option<Pubkey>)
+        8 +  // entry_fee
+        1 +  // best_of
+        1 +  // wins_a
+        1 +  // wins_b
+        1 +  // matches_played
+        1 +  // status (enum)
+        8 +  // prediction_window
+        8 +  // match_duration
+        1 + 8 + // current_match_id (Option<u64>)
+        8 +  // total_pot
+        8 +  // prize_pool
+        1 + 32 + // winner (Option<Pubkey>)
+        1 +  // claimed_a
+        1 +  // claimed_b
+        8 +  // created_at
+        1 + 8 + // completed_at (Option<i64>)
+        1 +  // bump
+        1 +  // version
+        1 + 2 + // protocol_fee_bps (Option<u16>)
+        61;  // reserved
+
+    /// Current account layout version
+    pub const VERSION: u8 = 1;
+
+    /// Fee charged on the pot: its market's override, else the config fee
+    pub fn fee_bps(&self, config_fee_bps: u16) -> u16 {
+        self.protocol_fee_bps.unwrap_or(config_fee_bps)
+    }
+
+    /// Check if `player` is one of the two players
+    pub fn is_player(&self, player: &Pubkey) -> bool {
+        *player == self.player_a || self.player_b.as_ref() == Some(player)
+    }
+}
+
+/// Config change waiting out the admin timelock
+/// PDA: ["queued-action", action_id.to_le_bytes()]
+#[account]
+pub struct QueuedAction {
+    /// Unique action identifier
+    pub action_id: u64,
+
+    /// Change to apply
+    pub action: ConfigAction,
+
+    /// Signer that queued the change
+    pub proposer: Pubkey,
+
+    /// Earliest execution timestamp
+    pub eta: i64,
+
+    /// Queue timestamp
+    pub created_at: i64,
+
+    /// PDA bump
+    pub bump: u8,
+
+    /// Account layout version
+    pub version: u8,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 29],
+}
+
+impl QueuedAction {
+    pub const LEN: usize = 8 +  // discriminator
+        8 +  // action_id
+        1 + 35 + // action (enum, largest variant)
+        32 + // proposer
+        8 +  // eta
+        8 +  // created_at
+        1 +  // bump
+        1 +  // version
+        29;  // reserved
+
+    /// Current account layout version
+    pub const VERSION: u8 = 1;
+
+    /// Check if the timelock has elapsed
+    pub fn is_ready(&self, now: i64) -> bool {
+        now >= self.eta
+    }
+}
+
+#[cfg(test)]
+mod queued_action_tests {
+    use super::*;
+
+    #[test]
+    fn test_queued_action_fits_every_config_action() {
+        let actions = [
+            ConfigAction::SetProtocolFee { protocol_fee_bps: u16::MAX },
+            ConfigAction::SetTreasury { treasury: Pubkey::new_unique() },
+            ConfigAction::SetAdminDelay { admin_delay: i64::MAX },
+            ConfigAction::SetLobbyTimeout { lobby_timeout: i64::MAX },
+            ConfigAction::SetKeeperReward { keeper_reward: u64::MAX },
+            ConfigAction::SetSolUsdFeed { sol_usd_feed: Pubkey::new_unique() },
+            ConfigAction::SetFeeTier {
+                index: u8::MAX,
+                fee_tier: FeeTier { min_level: u16::MAX, min_volume: u64::MAX, discount_bps: u16::MAX },
+            },
+            ConfigAction::SetReferralShare { referral_share_bps: u16::MAX },
+            ConfigAction::SetMaxCreatorFee { max_creator_fee_bps: u16::MAX },
+            ConfigAction::SetFeeRecipient {
+                index: u8::MAX,
+                fee_recipient: FeeRecipient { recipient: Pubkey::new_unique(), share_bps: u16::MAX },
+            },
+        ];
+
+        for action in actions {
+            let queued_action = QueuedAction {
+                action_id: u64::MAX,
+                action,
+                proposer: Pubkey::new_unique(),
+                eta: i64::MAX,
+                created_at: i64::MAX,
+                bump: 255,
+                version: QueuedAction::VERSION,
+                reserved: [0; 29],
+            };
+            let mut data = Vec::new();
+            queued_action.try_serialize(&mut data).unwrap();
+            assert!(data.len() <= QueuedAction::LEN, "{:?} needs {} bytes", action, data.len());
+        }
+    }
+}
+
+/// Role delegated by the config authority
+/// PDA: ["role", role.seed(), holder.key()]
+#[account]
+pub struct RoleAssignment {
+    /// Wallet holding the role
+    pub holder: Pubkey,
+
+    /// Role granted
+    pub role: Role,
+
+    /// Authority that granted the role
+    pub granted_by: Pubkey,
+
+    /// Grant timestamp
+    pub granted_at: i64,
+
+    /// PDA bump
+    pub bump: u8,
+
+    /// Account layout version
+    pub version: u8,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 32],
+}
+
+impl RoleAssignment {
+    pub const LEN: usize = 8 +  // discriminator
+        32 + // holder
+        1 +  // role (enum)
+        32 + // granted_by
+        8 +  // granted_at
+        1 +  // bump
+        1 +  // version
+        32;  // reserved
+
+    /// Current account layout version
+    pub const VERSION: u8 = 1;
 }
 
 /// Market definition for different asset pairs
@@ -145,6 +910,25 @@ pub struct Market {
 
     /// PDA bump
     pub bump: u8,
+
+    /// Account layout version
+    pub version: u8,
+
+    /// Price-change bucket boundaries in bps for range predictions
+    /// (ascending; N boundaries define N + 1 buckets, empty = not offered)
+    pub bucket_bounds_bps: Vec<i32>,
+
+    /// Recurring match schedules spawned by `spawn_template_match`
+    pub templates: Vec<MatchTemplate>,
+
+    /// SPL mint every match on this market must stake in (None = SOL)
+    pub accepted_mint: Option<Pubkey>,
+
+    /// Fee and match limits overriding the protocol-wide ones
+    pub limits: MarketLimits,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 11],
 }
 
 impl Market {
@@ -156,7 +940,17 @@ impl Market {
         1 +   // active
         8 +   // total_matches
         8 +   // created_at
-        1;    // bump
+        1 +   // bump
+        1 +   // version
+        4 + 4 * (constants::MAX_PRICE_BUCKETS - 1) + // bucket_bounds_bps
+        4 + MatchTemplate::LEN * constants::MAX_MARKET_TEMPLATES + // templates
+        1 + 32 + // accepted_mint (Option<Pubkey>)
+        MarketLimits::LEN + // limits
+        11;   // reserved
+
+    /// Current account layout version
+    /// v2: match templates
+    pub const VERSION: u8 = 2;
 
     /// Helper to get name as string
     pub fn get_name(&self) -> Result<String> {
@@ -230,6 +1024,97 @@ pub struct Match {
 
     /// PDA bump
     pub bump: u8,
+
+    /// Account layout version
+    pub version: u8,
+
+    /// Access policy for private matches (None = public lobby)
+    pub access: Option<MatchAccess>,
+
+    /// Bucket boundaries copied from the market (empty = Higher/Lower match)
+    pub bucket_bounds_bps: Vec<i32>,
+
+    /// Number of locked predictions per bucket
+    pub bucket_counts: Vec<u8>,
+
+    /// Closest-price payout share per tier in bps (empty = not a closest-price match)
+    pub payout_tiers_bps: Vec<u16>,
+
+    /// Target prices in submission order (closest-price matches)
+    pub targets: Vec<u64>,
+
+    /// Distance from the end price of each paid tier (set at resolution)
+    pub tier_distances: Vec<u64>,
+
+    /// Number of players in each paid tier (set at resolution)
+    pub tier_counts: Vec<u8>,
+
+    /// Predictions are committed as hashes and revealed after the window
+    pub sealed: bool,
+
+    /// Second market of a relative-performance match (None = single market)
+    pub market_b: Option<Pubkey>,
+
+    /// Second market's starting price
+    pub start_price_b: Option<u64>,
+
+    /// Second market's ending price
+    pub end_price_b: Option<u64>,
+
+    /// Move threshold in bps for volatility matches (None = direction match)
+    pub volatility_threshold_bps: Option<u16>,
+
+    /// Number of elimination rounds (0 = single-round match)
+    pub elimination_rounds: u8,
+
+    /// Current elimination round (1-based)
+    pub current_round: u8,
+
+    /// Start of the current round's prediction window
+    pub round_started_at: i64,
+
+    /// Start/end price and survivors of each elimination round
+    pub rounds: Vec<EliminationRound>,
+
+    /// Players still in, by join index
+    pub alive_mask: u16,
+
+    /// Players who predicted Higher this round, by join index
+    pub higher_mask: u16,
+
+    /// Players who predicted Lower this round, by join index
+    pub lower_mask: u16,
+
+    /// House bonus seeded into the vault by a market template (paid to winners, no fee)
+    pub house_bonus: u64,
+
+    /// SPL mint the stakes are escrowed in (None = SOL)
+    pub mint: Option<Pubkey>,
+
+    /// Entry fee pegged to USD, converted to lamports at each join
+    /// (`entry_fee` then holds what the creator paid)
+    pub usd_entry_fee: Option<UsdEntryFee>,
+
+    /// Protocol fee override of the match's market at creation (None = config fee)
+    pub protocol_fee_bps: Option<u16>,
+
+    /// Creator's cut of each winner's payout, in bps of the prize pool
+    pub creator_fee_bps: u16,
+
+    /// Paid out of the vault by claims so far, fees included
+    /// (None = created before payouts were tracked)
+    pub paid_out: Option<u64>,
+
+    /// Locked predictions per side of a two-sided match (Higher, AOutperforms
+    /// and MovesBeyond first; None = created before sides were counted)
+    pub side_counts: Option<[u8; 2]>,
+
+    /// Stake of the players who revealed a sealed prediction
+    /// (None = created before reveals were tallied)
+    pub revealed_stake: Option<u64>,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 20],
 }
 
 impl Match {
@@ -246,12 +1131,109 @@ impl Match {
         1 + 8 + // end_price (Option<u64>)
         8 +  // prediction_window
         8 +  // resolution_time
-        1 + 1 + // winning_side (Option<PredictionSide>)
+        1 + PredictionSide::MAX_LEN + // winning_side (Option<PredictionSide>)
         8 +  // total_pot
         8 +  // created_at
         1 + 8 + // started_at (Option<i64>)
         1 + 8 + // resolved_at (Option<i64>)
-        1;   // bump
+        1 +  // bump
+        1 +  // version
+        1 + MatchAccess::MAX_LEN + // access (Option<MatchAccess>)
+        4 + 4 * (constants::MAX_PRICE_BUCKETS - 1) + // bucket_bounds_bps
+        4 + constants::MAX_PRICE_BUCKETS + // bucket_counts
+        4 + 2 * constants::MAX_PAYOUT_TIERS + // payout_tiers_bps
+        4 + 8 * constants::MAX_PLAYERS as usize + // targets
+        4 + 8 * constants::MAX_PAYOUT_TIERS + // tier_distances
+        4 + constants::MAX_PAYOUT_TIERS + // tier_counts
+        1 +  // sealed
+        1 + 32 + // market_b (Option<Pubkey>)
+        1 + 8 + // start_price_b (Option<u64>)
+        1 + 8 + // end_price_b (Option<u64>)
+        1 + 2 + // volatility_threshold_bps (Option<u16>)
+        1 +  // elimination_rounds
+        1 +  // current_round
+        8 +  // round_started_at
+        4 + EliminationRound::LEN * constants::MAX_ELIMINATION_ROUNDS as usize + // rounds
+        2 +  // alive_mask
+        2 +  // higher_mask
+        2 +  // lower_mask
+        8 +  // house_bonus
+        1 + 32 + // mint (Option<Pubkey>)
+        1 + UsdEntryFee::LEN + // usd_entry_fee (Option<UsdEntryFee>)
+        1 + 2 + // protocol_fee_bps (Option<u16>)
+        2 +  // creator_fee_bps
+        1 + 8 + // paid_out (Option<u64>)
+        1 + 2 + // side_counts (Option<[u8; 2]>)
+        1 + 8 + // revealed_stake (Option<u64>)
+        20;  // reserved
+
+    /// Current account layout version
+    /// v2: access policy
+    /// v3: closest-price targets and payout tiers
+    /// v4: elimination rounds
+    pub const VERSION: u8 = 4;
+
+    /// Open Higher/Lower lobby with no players, pot or mode settings yet
+    /// Every match starts from here (callers override what their mode needs)
+    /// so a new field only needs its default set in one place
+    pub fn new(
+        match_id: u64,
+        market: Pubkey,
+        creator: Pubkey,
+        match_type: MatchType,
+        bump: u8,
+        now: i64,
+    ) -> Self {
+        Self {
+            match_id,
+            market,
+            creator,
+            match_type,
+            entry_fee: 0,
+            max_players: 0,
+            current_players: 0,
+            status: MatchStatus::Open,
+            start_price: None,
+            end_price: None,
+            prediction_window: 0,
+            resolution_time: now,
+            winning_side: None,
+            total_pot: 0,
+            created_at: now,
+            started_at: None,
+            resolved_at: None,
+            bump,
+            version: Self::VERSION,
+            access: None,
+            bucket_bounds_bps: Vec::new(),
+            bucket_counts: Vec::new(),
+            payout_tiers_bps: Vec::new(),
+            targets: Vec::new(),
+            tier_distances: Vec::new(),
+            tier_counts: Vec::new(),
+            sealed: false,
+            market_b: None,
+            start_price_b: None,
+            end_price_b: None,
+            volatility_threshold_bps: None,
+            elimination_rounds: 0,
+            current_round: 1,
+            round_started_at: now,
+            rounds: Vec::new(),
+            alive_mask: 0,
+            higher_mask: 0,
+            lower_mask: 0,
+            house_bonus: 0,
+            mint: None,
+            usd_entry_fee: None,
+            protocol_fee_bps: None,
+            creator_fee_bps: 0,
+            paid_out: Some(0),
+            side_counts: Some([0; 2]),
+            revealed_stake: Some(0),
+            reserved: [0; 20],
+        }
+    }
 
     /// Check if match is full
     pub fn is_full(&self) -> bool {
@@ -271,14 +1253,173 @@ impl Match {
         Clock::get().unwrap().unix_timestamp >= self.resolution_time
     }
 
+    /// Check if the match is hidden from public lobbies
+    pub fn is_private(&self) -> bool {
+        self.access.is_some()
+    }
+
+    /// Check if `player` may join, revealing `passphrase` when required
+    pub fn admits(&self, player: &Pubkey, passphrase: Option<&[u8]>) -> bool {
+        match &self.access {
+            None => true,
+            Some(MatchAccess::Allowlist { players }) => players.contains(player),
+            Some(MatchAccess::Passphrase { hash }) => matches!(
+                passphrase,
+                Some(p) if anchor_lang::solana_program::hash::hash(p).to_bytes() == *hash
+            ),
+        }
+    }
+
+    /// Check if players pick a price-change bucket instead of Higher/Lower
+    pub fn is_bucket_mode(&self) -> bool {
+        !self.bucket_bounds_bps.is_empty()
+    }
+
+    /// Check if players submit exact target prices
+    pub fn is_closest_price_mode(&self) -> bool {
+        !self.payout_tiers_bps.is_empty()
+    }
+
+    /// Check if players compare two markets' percent change
+    pub fn is_relative_mode(&self) -> bool {
+        self.market_b.is_some()
+    }
+
+    /// Check if players predict the size of the move rather than its direction
+    pub fn is_volatility_mode(&self) -> bool {
+        self.volatility_threshold_bps.is_some()
+    }
+
+    /// Check if stakes are escrowed in an SPL token instead of SOL
+    pub fn is_token_match(&self) -> bool {
+        self.mint.is_some()
+    }
+
+    /// Check if players are knocked out over several rounds
+    pub fn is_elimination_mode(&self) -> bool {
+        self.elimination_rounds > 0
+    }
+
+    /// Check if the player at `index` is still in (or survived to the end)
+    pub fn is_survivor(&self, index: u8) -> bool {
+        self.alive_mask & (1 << index) != 0
+    }
+
+    /// Resolved without a winning side: every stake is refunded
+    /// Elimination matches always pay their survivors, even after a flat last round
+    pub fn is_refund(&self) -> bool {
+        self.winning_side.is_none() && !self.is_elimination_mode()
+    }
+
+    /// Check if an entry won a resolved match (elimination: survived the last round)
+    pub fn is_winning_entry(&self, prediction: Option<PredictionSide>, player_index: u8) -> bool {
+        if self.is_elimination_mode() {
+            self.is_survivor(player_index)
+        } else {
+            self.winning_side.is_some() && prediction == self.winning_side
+        }
+    }
+
+    /// End of the current elimination round
+    /// Every round is as long as the first (created_at..resolution_time)
+    pub fn round_ends_at(&self) -> i64 {
+        self.round_started_at + (self.resolution_time - self.created_at)
+    }
+
+    /// Check if `prediction` is a valid pick for this match
+    pub fn accepts_prediction(&self, prediction: PredictionSide) -> bool {
+        match prediction {
+            PredictionSide::Bucket(index) => (index as usize) < self.bucket_counts.len(),
+            PredictionSide::TargetPrice(price) => self.is_closest_price_mode() && price > 0,
+            PredictionSide::AOutperforms | PredictionSide::BOutperforms => self.is_relative_mode(),
+            PredictionSide::MovesBeyond | PredictionSide::StaysWithin => self.is_volatility_mode(),
+            PredictionSide::Higher | PredictionSide::Lower => {
+                !self.is_bucket_mode() &&
+                !self.is_closest_price_mode() &&
+                !self.is_relative_mode() &&
+                !self.is_volatility_mode() &&
+                !self.is_elimination_mode()
+            },
+        }
+    }
+
+    /// Check if sealed predictions can be revealed (window closed, not yet resolved)
+    pub fn is_reveal_open(&self, now: i64) -> bool {
+        now > self.created_at + self.prediction_window && now < self.resolution_time
+    }
+
+    /// Record a locked prediction in the match's on-chain counters
+    pub fn record_prediction(&mut self, prediction: PredictionSide) -> Result<()> {
+        match prediction {
+            PredictionSide::Bucket(index) => {
+                let count = &mut self.bucket_counts[index as usize];
+                *count = count.checked_add(1).ok_or(ErrorCode::ArithmeticOverflow)?;
+            },
+            PredictionSide::TargetPrice(target) => self.targets.push(target),
+            side => {
+                if let (Some(counts), Some(index)) = (self.side_counts.as_mut(), side.side_index()) {
+                    counts[index] = counts[index].checked_add(1)
+                        .ok_or(ErrorCode::ArithmeticOverflow)?;
+                }
+            },
+        }
+        Ok(())
+    }
+
+    /// Locked predictions on a two-sided pick (None = not counted on this match)
+    pub fn side_count(&self, side: PredictionSide) -> Option<u8> {
+        Some(self.side_counts?[side.side_index()?])
+    }
+
+    /// Winnings for a closest-price target once the match is resolved
+    pub fn closest_price_payout(&self, target: u64, prize_pool: u64) -> u64 {
+        let Some(end_price) = self.end_price else {
+            return 0;
+        };
+
+        utils::closest_price::tier_payout(
+            &self.payout_tiers_bps,
+            &self.tier_distances,
+            &self.tier_counts,
+            target.abs_diff(end_price),
+            prize_pool,
+        )
+    }
+
+    /// Timestamp after which an unfilled Open match can be expired
+    /// Never earlier than the end of the prediction window
+    pub fn lobby_expires_at(&self, lobby_timeout: i64) -> i64 {
+        self.created_at + self.prediction_window.max(lobby_timeout)
+    }
+
+    /// Fee charged on this match: its market's override, else the config fee
+    pub fn fee_bps(&self, config_fee_bps: u16) -> u16 {
+        self.protocol_fee_bps.unwrap_or(config_fee_bps)
+    }
+
+    /// Count `amount` paid out of the vault by a claim
+    pub fn record_payout(&mut self, amount: u64) -> Result<()> {
+        if let Some(paid_out) = self.paid_out.as_mut() {
+            *paid_out = paid_out.checked_add(amount)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+        }
+        Ok(())
+    }
+
+    /// What the vault still owes unclaimed entries (None = payouts not tracked)
+    pub fn still_owed(&self) -> Option<u64> {
+        let paid_out = self.paid_out?;
+        Some(self.total_pot.saturating_add(self.house_bonus).saturating_sub(paid_out))
+    }
+
     /// Calculate protocol fee for this match
     pub fn calculate_protocol_fee(&self, fee_bps: u16) -> u64 {
         (self.total_pot as u128 * fee_bps as u128 / 10000) as u64
     }
 
-    /// Calculate prize pool after fees
+    /// Calculate prize pool after fees (the house bonus is not charged)
     pub fn calculate_prize_pool(&self, fee_bps: u16) -> u64 {
-        self.total_pot - self.calculate_protocol_fee(fee_bps)
+        self.total_pot - self.calculate_protocol_fee(fee_bps) + self.house_bonus
     }
 }
 
@@ -309,22 +1450,49 @@ pub struct PlayerEntry {
 
     /// PDA bump
     pub bump: u8,
+
+    /// Account layout version
+    pub version: u8,
+
+    /// Hash of (prediction, salt) on sealed matches until revealed
+    pub commitment: Option<[u8; 32]>,
+
+    /// Join order within the match (bit index in elimination masks)
+    pub player_index: u8,
+
+    /// Jackpot lamports won when claiming and not yet withdrawn
+    pub jackpot_award: u64,
+
+    /// Counted in the player's `open_entries` until settled
+    pub in_open_entries: bool,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 13],
 }
 
 impl PlayerEntry {
     pub const LEN: usize = 8 +  // discriminator
         32 + // match_account
         32 + // player
-        1 + 1 + // prediction (Option<PredictionSide>)
+        1 + PredictionSide::MAX_LEN + // prediction (Option<PredictionSide>)
         8 +  // amount_staked
         1 + 8 + // prediction_locked_at (Option<i64>)
         1 +  // claimed
         8 +  // winnings
-        1;   // bump
+        1 +  // bump
+        1 +  // version
+        1 + 32 + // commitment (Option<[u8; 32]>)
+        1 +  // player_index
+        8 +  // jackpot_award
+        1 +  // in_open_entries
+        13;  // reserved
+
+    /// Current account layout version
+    pub const VERSION: u8 = 1;
 
     /// Check if prediction can be made
     pub fn can_predict(&self) -> bool {
-        self.prediction.is_none() && !self.claimed
+        self.prediction.is_none() && self.commitment.is_none() && !self.claimed
     }
 
     /// Check if winnings can be claimed
@@ -375,6 +1543,36 @@ pub struct UserProfile {
 
     /// PDA bump
     pub bump: u8,
+
+    /// Account layout version
+    pub version: u8,
+
+    /// Elimination rounds survived across all matches
+    pub rounds_survived: u64,
+
+    /// Volume epoch (`VOLUME_EPOCH_SECONDS` long) of `epoch_volume`
+    pub volume_epoch: u32,
+
+    /// Lamports wagered in `volume_epoch`
+    pub epoch_volume: u64,
+
+    /// Lamports wagered in the epoch before `volume_epoch`
+    pub prev_epoch_volume: u64,
+
+    /// Wallet that referred this player (set once)
+    pub referrer: Option<Pubkey>,
+
+    /// Entries joined and not yet settled (claimed, refunded or left)
+    pub open_entries: u16,
+
+    /// Wins in a row in resolution order, towards the jackpot
+    pub jackpot_streak: u16,
+
+    /// Resolution time of the latest result counted in `jackpot_streak`
+    pub last_settled_at: i64,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 32],
 }
 
 impl UserProfile {
@@ -391,7 +1589,21 @@ impl UserProfile {
         8 +  // xp
         2 +  // level
         8 +  // created_at
-        1;   // bump
+        1 +  // bump
+        1 +  // version
+        8 +  // rounds_survived
+        4 +  // volume_epoch
+        8 +  // epoch_volume
+        8 +  // prev_epoch_volume
+        1 + 32 + // referrer (Option<Pubkey>)
+        2 +  // open_entries
+        2 +  // jackpot_streak
+        8 +  // last_settled_at
+        32;  // reserved
+
+    /// Current account layout version
+    /// v2: open entries and jackpot streak
+    pub const VERSION: u8 = 2;
 
     /// Calculate win rate as percentage
     pub fn win_rate(&self) -> f64 {
@@ -406,6 +1618,27 @@ impl UserProfile {
         self.total_won as i64 - self.total_wagered as i64
     }
 
+    /// Volume over the current and previous volume epoch, for fee tiers
+    pub fn rolling_volume(&self, now: i64) -> u64 {
+        utils::fee_tiers::rolling_volume(
+            self.volume_epoch,
+            self.epoch_volume,
+            self.prev_epoch_volume,
+            volume_epoch_at(now),
+        )
+    }
+
+    /// Add a settled stake to the rolling volume
+    pub fn record_volume(&mut self, amount: u64, now: i64) {
+        (self.volume_epoch, self.epoch_volume, self.prev_epoch_volume) = utils::fee_tiers::record_volume(
+            self.volume_epoch,
+            self.epoch_volume,
+            self.prev_epoch_volume,
+            volume_epoch_at(now),
+            amount,
+        );
+    }
+
     /// Update streak on match result
     pub fn update_streak(&mut self, won: bool) {
         if won {
@@ -424,17 +1657,256 @@ impl UserProfile {
         }
     }
 
-    /// Calculate level from XP (simple formula: level = sqrt(xp / 1000))
-    pub fn calculate_level(&self) -> u16 {
-        ((self.xp as f64 / 1000.0).sqrt() as u16).max(1)
+    /// Count a new entry in `entry` as open until it is settled
+    pub fn open_entry(&mut self, entry: &mut PlayerEntry) -> Result<()> {
+        self.open_entries = self.open_entries.checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        entry.in_open_entries = true;
+        Ok(())
+    }
+
+    /// Take a claimed, refunded or abandoned entry off `open_entries`
+    pub fn settle_entry(&mut self, entry: &mut PlayerEntry) {
+        if entry.in_open_entries {
+            self.open_entries = self.open_entries.saturating_sub(1);
+            entry.in_open_entries = false;
+        }
+    }
+
+    /// Count a win or loss of a match resolved at `resolved_at` towards the jackpot streak
+    pub fn record_jackpot_result(&mut self, won: bool, resolved_at: i64) {
+        self.jackpot_streak = utils::jackpot::next_jackpot_streak(
+            self.jackpot_streak,
+            self.last_settled_at,
+            won,
+            resolved_at,
+        );
+        self.last_settled_at = self.last_settled_at.max(resolved_at);
+    }
+
+    /// Calculate level from XP (simple formula: level = sqrt(xp / 1000))
+    pub fn calculate_level(&self) -> u16 {
+        ((self.xp as f64 / 1000.0).sqrt() as u16).max(1)
+    }
+
+    /// Get username as string
+    pub fn get_username(&self) -> Option<String> {
+        self.username.and_then(|name| {
+            let end = name.iter().position(|&c| c == 0).unwrap_or(32);
+            String::from_utf8(name[..end].to_vec()).ok()
+        })
+    }
+}
+
+/// Referral balance of a wallet that refers players
+/// Holds its accrued fee share in lamports until claimed
+/// PDA: ["referrer", referrer.key()]
+#[account]
+pub struct Referrer {
+    /// Referring wallet
+    pub referrer: Pubkey,
+
+    /// Players that registered this referrer
+    pub referred_count: u64,
+
+    /// Lamports of fee share accrued over all time
+    pub total_accrued: u64,
+
+    /// Lamports claimed over all time
+    pub total_claimed: u64,
+
+    /// Registration timestamp
+    pub created_at: i64,
+
+    /// PDA bump
+    pub bump: u8,
+
+    /// Account layout version
+    pub version: u8,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 64],
+}
+
+impl Referrer {
+    pub const LEN: usize = 8 +  // discriminator
+        32 + // referrer
+        8 +  // referred_count
+        8 +  // total_accrued
+        8 +  // total_claimed
+        8 +  // created_at
+        1 +  // bump
+        1 +  // version
+        64;  // reserved
+
+    /// Current account layout version
+    pub const VERSION: u8 = 1;
+
+    /// Accrued lamports not yet claimed
+    pub fn claimable(&self) -> u64 {
+        self.total_accrued.saturating_sub(self.total_claimed)
+    }
+}
+
+/// Protocol fee vault: collects fees (lamports above rent, and token fees in
+/// its associated token accounts) until `distribute_fees` splits them
+/// PDA: ["fee-vault"]
+#[account]
+pub struct FeeVault {
+    /// Fee recipients and their shares; the unallocated share goes to the treasury
+    pub recipients: [FeeRecipient; constants::MAX_FEE_RECIPIENTS],
+
+    /// Lamports distributed over all time
+    pub total_distributed: u64,
+
+    /// PDA bump
+    pub bump: u8,
+
+    /// Account layout version
+    pub version: u8,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 64],
+}
+
+impl FeeVault {
+    pub const LEN: usize = 8 +  // discriminator
+        FeeRecipient::LEN * constants::MAX_FEE_RECIPIENTS + // recipients
+        8 +  // total_distributed
+        1 +  // bump
+        1 +  // version
+        64;  // reserved
+
+    /// Current account layout version
+    pub const VERSION: u8 = 1;
+
+    /// Recipients with a non-zero share, in slot order
+    pub fn active_recipients(&self) -> Vec<FeeRecipient> {
+        self.recipients.iter()
+            .filter(|recipient| recipient.share_bps > 0)
+            .copied()
+            .collect()
+    }
+}
+
+/// Protocol insurance fund: lamports above rent, paid in through the fee
+/// vault split or by deposit, used to cover match vault shortfalls
+/// PDA: ["insurance-fund"]
+#[account]
+pub struct InsuranceFund {
+    /// Lamports paid in over all time
+    pub total_deposited: u64,
+
+    /// Lamports paid out to match vaults over all time
+    pub total_covered: u64,
+
+    /// Number of shortfalls covered
+    pub shortfalls_covered: u64,
+
+    /// PDA bump
+    pub bump: u8,
+
+    /// Account layout version
+    pub version: u8,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 64],
+}
+
+impl InsuranceFund {
+    pub const LEN: usize = 8 +  // discriminator
+        8 +  // total_deposited
+        8 +  // total_covered
+        8 +  // shortfalls_covered
+        1 +  // bump
+        1 +  // version
+        64;  // reserved
+
+    /// Current account layout version
+    pub const VERSION: u8 = 1;
+
+    /// Count lamports that were just paid in
+    pub fn record_deposit(&mut self, amount: u64) -> Result<()> {
+        self.total_deposited = self.total_deposited.checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+/// Progressive jackpot: lamports above rent, paid in through the fee vault
+/// split and awarded to players who hit a rare outcome when claiming
+/// PDA: ["jackpot"]
+#[account]
+pub struct Jackpot {
+    /// Lamports paid in over all time
+    pub total_funded: u64,
+
+    /// Lamports awarded to hits over all time
+    pub total_awarded: u64,
+
+    /// Lamports withdrawn by winners over all time
+    pub total_claimed: u64,
+
+    /// Number of hits
+    pub hits: u64,
+
+    /// PDA bump
+    pub bump: u8,
+
+    /// Account layout version
+    pub version: u8,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 64],
+}
+
+impl Jackpot {
+    pub const LEN: usize = 8 +  // discriminator
+        8 +  // total_funded
+        8 +  // total_awarded
+        8 +  // total_claimed
+        8 +  // hits
+        1 +  // bump
+        1 +  // version
+        64;  // reserved
+
+    /// Current account layout version
+    pub const VERSION: u8 = 1;
+
+    /// Count lamports that were just paid in
+    pub fn record_funding(&mut self, amount: u64) -> Result<()> {
+        self.total_funded = self.total_funded.checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Awarded lamports not yet withdrawn
+    pub fn outstanding(&self) -> u64 {
+        self.total_awarded.saturating_sub(self.total_claimed)
+    }
+
+    /// Part of `balance` (lamports above rent) not promised to earlier hits
+    pub fn unreserved(&self, balance: u64) -> u64 {
+        balance.saturating_sub(self.outstanding())
     }
 
-    /// Get username as string
-    pub fn get_username(&self) -> Option<String> {
-        self.username.and_then(|name| {
-            let end = name.iter().position(|&c| c == 0).unwrap_or(32);
-            String::from_utf8(name[..end].to_vec()).ok()
-        })
+    /// Set `amount` aside for a hit
+    pub fn record_award(&mut self, amount: u64) -> Result<()> {
+        self.total_awarded = self.total_awarded.checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        self.hits = self.hits.checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Pay out `amount` of an award, given the lamports above rent
+    /// Awards are reserved when won, so a shortfall means the jackpot lost funds
+    pub fn record_claim(&mut self, amount: u64, balance: u64) -> Result<()> {
+        require!(amount <= self.outstanding(), ErrorCode::NoJackpotAward);
+        require!(amount <= balance, ErrorCode::InsufficientJackpotFunds);
+        self.total_claimed = self.total_claimed.checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
     }
 }
 
@@ -453,6 +1925,253 @@ pub enum MatchType {
 
     /// Structured tournament bracket
     Tournament,
+
+    /// One round of a best-of-N series
+    Series,
+}
+
+/// One round of an elimination BattleRoyale
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct EliminationRound {
+    /// Price at the start of the round
+    pub start_price: u64,
+
+    /// Price at the end of the round (None while in play)
+    pub end_price: Option<u64>,
+
+    /// Winning side (None if the price did not move)
+    pub winning_side: Option<PredictionSide>,
+
+    /// Players still in after this round, by join index
+    pub survivors: u16,
+}
+
+impl EliminationRound {
+    pub const LEN: usize = 8 + // start_price
+        1 + 8 + // end_price (Option<u64>)
+        1 + PredictionSide::MAX_LEN + // winning_side (Option<PredictionSide>)
+        2;  // survivors
+}
+
+/// USD-denominated entry fee of a SOL match
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct UsdEntryFee {
+    /// Entry fee in US cents
+    pub cents: u64,
+
+    /// SOL/USD price account the fee is converted with (from GameConfig at creation)
+    pub price_feed: Pubkey,
+}
+
+impl UsdEntryFee {
+    pub const LEN: usize = 8 + // cents
+        32; // price_feed
+}
+
+/// Per-market overrides of the protocol fee and `create_match` limits
+/// Zero fields (and a None fee) fall back to the protocol-wide value
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct MarketLimits {
+    /// Protocol fee in bps for matches on this market
+    pub protocol_fee_bps: Option<u16>,
+
+    /// Minimum entry fee (lamports, or base units on token markets)
+    pub min_entry_fee: u64,
+
+    /// Maximum entry fee (lamports, or base units on token markets)
+    pub max_entry_fee: u64,
+
+    /// Bitmask of allowed match types, bit = `MatchType as u8` (0 = all)
+    pub allowed_match_types: u8,
+
+    /// Minimum prediction window in seconds
+    pub min_prediction_window: i64,
+
+    /// Maximum prediction window in seconds
+    pub max_prediction_window: i64,
+
+    /// Minimum duration after the prediction window
+    pub min_match_duration: i64,
+
+    /// Maximum duration after the prediction window
+    pub max_match_duration: i64,
+}
+
+impl MarketLimits {
+    pub const LEN: usize = 1 + 2 + // protocol_fee_bps (Option<u16>)
+        8 +  // min_entry_fee
+        8 +  // max_entry_fee
+        1 +  // allowed_match_types
+        8 +  // min_prediction_window
+        8 +  // max_prediction_window
+        8 +  // min_match_duration
+        8;   // max_match_duration
+
+    /// Whether matches of `match_type` may be created on the market
+    pub fn allows(&self, match_type: MatchType) -> bool {
+        self.allowed_match_types == 0 || self.allowed_match_types & (1 << match_type as u8) != 0
+    }
+
+    /// Allowed entry fees; token markets have no protocol-wide bounds
+    pub fn entry_fee_range(&self, token_market: bool) -> RangeInclusive<u64> {
+        let (min, max) = if token_market {
+            (1, u64::MAX)
+        } else {
+            (constants::MIN_ENTRY_FEE, constants::MAX_ENTRY_FEE)
+        };
+        utils::limits::override_range(self.min_entry_fee, self.max_entry_fee, min, max)
+    }
+
+    /// Allowed prediction windows
+    pub fn prediction_window_range(&self) -> RangeInclusive<i64> {
+        utils::limits::override_range(
+            self.min_prediction_window,
+            self.max_prediction_window,
+            constants::MIN_PREDICTION_WINDOW,
+            constants::MAX_PREDICTION_WINDOW,
+        )
+    }
+
+    /// Allowed match durations
+    pub fn match_duration_range(&self) -> RangeInclusive<i64> {
+        utils::limits::override_range(
+            self.min_match_duration,
+            self.max_match_duration,
+            constants::MIN_MATCH_DURATION,
+            constants::MAX_MATCH_DURATION,
+        )
+    }
+}
+
+/// Fee discount tier: applies once a player reaches either threshold
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct FeeTier {
+    /// Minimum player level (0 = no level threshold)
+    pub min_level: u16,
+
+    /// Minimum rolling volume in lamports (0 = no volume threshold)
+    pub min_volume: u64,
+
+    /// Discount on the protocol fee in bps (0 = tier unused)
+    pub discount_bps: u16,
+}
+
+impl FeeTier {
+    pub const LEN: usize = 2 + // min_level
+        8 +  // min_volume
+        2;   // discount_bps
+}
+
+/// Recipient of a share of protocol fees
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct FeeRecipient {
+    /// Wallet receiving the share, or the insurance fund / jackpot PDA
+    /// (the only program accounts whose inflows are booked and logged)
+    pub recipient: Pubkey,
+
+    /// Share of distributed fees in bps (0 = slot unused)
+    pub share_bps: u16,
+}
+
+impl FeeRecipient {
+    pub const LEN: usize = 32 + // recipient
+        2;   // share_bps
+}
+
+/// Volume epoch containing `timestamp`
+pub fn volume_epoch_at(timestamp: i64) -> u32 {
+    (timestamp.max(0) / constants::VOLUME_EPOCH_SECONDS) as u32
+}
+
+/// Recurring match schedule defined on a market
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MatchTemplate {
+    /// Type of the spawned matches
+    pub match_type: MatchType,
+
+    /// Entry fee in lamports
+    pub entry_fee: u64,
+
+    /// Maximum players per match
+    pub max_players: u8,
+
+    /// Prediction window in seconds
+    pub prediction_window: i64,
+
+    /// Duration after the prediction window
+    pub match_duration: i64,
+
+    /// Seconds between spawns
+    pub cadence: i64,
+
+    /// Lamports seeded from the market's house funds into each spawned match
+    pub house_bonus: u64,
+
+    /// Earliest time the next match may be spawned
+    pub next_spawn_at: i64,
+
+    /// Whether the schedule is running
+    pub active: bool,
+}
+
+impl MatchTemplate {
+    pub const LEN: usize = 1 + // match_type
+        8 +  // entry_fee
+        1 +  // max_players
+        8 +  // prediction_window
+        8 +  // match_duration
+        8 +  // cadence
+        8 +  // house_bonus
+        8 +  // next_spawn_at
+        1;   // active
+}
+
+/// Who may join a private match
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum MatchAccess {
+    /// Only these wallets (direct challenge)
+    Allowlist { players: Vec<Pubkey> },
+
+    /// Anyone who reveals the preimage of this SHA-256 hash
+    Passphrase { hash: [u8; 32] },
+}
+
+impl MatchAccess {
+    /// Serialized size of the largest variant
+    pub const MAX_LEN: usize = 1 + // variant
+        4 + 32 * constants::MAX_ALLOWLIST_PLAYERS; // Vec<Pubkey>
+}
+
+/// Series lifecycle status
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SeriesStatus {
+    /// Waiting for an opponent
+    Open,
+
+    /// Rounds in play
+    InProgress,
+
+    /// Winner decided, prize claimable
+    Completed,
+
+    /// Cancelled or undecided after the replay limit (refunds issued)
+    Cancelled,
+}
+
+/// Tournament lifecycle status
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TournamentStatus {
+    /// Accepting registrations
+    Registration,
+
+    /// Bracket in play
+    InProgress,
+
+    /// Champion decided, prizes claimable
+    Completed,
+
+    /// Registration did not fill (refunds issued)
+    Cancelled,
 }
 
 /// Match lifecycle status
@@ -474,6 +2193,106 @@ pub enum MatchStatus {
     Cancelled,
 }
 
+/// Rare outcome that wins the jackpot
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum JackpotTrigger {
+    /// At least `JACKPOT_STREAK` wins in a row in resolution order, with no
+    /// other entries left unsettled
+    WinStreak,
+
+    /// Closest-price target equal to the end price
+    ExactPrice,
+}
+
+/// Operational roles the config authority can delegate
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    /// Create markets and toggle them active
+    MarketManager,
+
+    /// Cancel matches on behalf of players
+    Operator,
+
+    /// Queue protocol fee and treasury changes
+    FeeManager,
+
+    /// Pause the game in an emergency and veto queued changes
+    Guardian,
+
+    /// Cover match vault shortfalls from the insurance fund
+    InsuranceManager,
+}
+
+impl Role {
+    /// PDA seed for assignments of this role
+    pub fn seed(&self) -> &'static [u8] {
+        match self {
+            Role::MarketManager => b"market-manager",
+            Role::Operator => b"operator",
+            Role::FeeManager => b"fee-manager",
+            Role::Guardian => b"guardian",
+            Role::InsuranceManager => b"insurance-manager",
+        }
+    }
+}
+
+/// Config change that must go through the admin timelock
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ConfigAction {
+    /// Set protocol fee in basis points
+    SetProtocolFee { protocol_fee_bps: u16 },
+
+    /// Set treasury wallet
+    SetTreasury { treasury: Pubkey },
+
+    /// Set timelock delay in seconds
+    SetAdminDelay { admin_delay: i64 },
+
+    /// Set lobby timeout in seconds
+    SetLobbyTimeout { lobby_timeout: i64 },
+
+    /// Set keeper reward for expiring matches in lamports
+    SetKeeperReward { keeper_reward: u64 },
+
+    /// Set the SOL/USD price account for USD-pegged entry fees
+    SetSolUsdFeed { sol_usd_feed: Pubkey },
+
+    /// Set one fee discount tier
+    SetFeeTier { index: u8, fee_tier: FeeTier },
+
+    /// Set the referrer share of referred players' protocol fees in bps
+    SetReferralShare { referral_share_bps: u16 },
+
+    /// Set the cap on match creator fees in bps
+    SetMaxCreatorFee { max_creator_fee_bps: u16 },
+
+    /// Set one slot of the fee vault's recipient split
+    SetFeeRecipient { index: u8, fee_recipient: FeeRecipient },
+
+    /// Set the share of the jackpot awarded per hit in bps
+    SetJackpotPayout { jackpot_payout_bps: u16 },
+}
+
+impl ConfigAction {
+    /// Role allowed to queue this change besides the authority
+    /// (None means authority only)
+    pub fn delegated_role(&self) -> Option<Role> {
+        match self {
+            ConfigAction::SetProtocolFee { .. } |
+            ConfigAction::SetTreasury { .. } |
+            ConfigAction::SetKeeperReward { .. } |
+            ConfigAction::SetFeeTier { .. } |
+            ConfigAction::SetReferralShare { .. } |
+            ConfigAction::SetMaxCreatorFee { .. } |
+            ConfigAction::SetFeeRecipient { .. } |
+            ConfigAction::SetJackpotPayout { .. } => Some(Role::FeeManager),
+            ConfigAction::SetAdminDelay { .. } |
+            ConfigAction::SetLobbyTimeout { .. } |
+            ConfigAction::SetSolUsdFeed { .. } => None,
+        }
+    }
+}
+
 /// Prediction direction
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum PredictionSide {
@@ -482,6 +2301,38 @@ pub enum PredictionSide {
 
     /// Price will go lower
     Lower,
+
+    /// Price change lands in this bucket (range prediction matches)
+    Bucket(u8),
+
+    /// Exact end price guess (closest-price matches)
+    TargetPrice(u64),
+
+    /// First market's percent change beats the second's (relative matches)
+    AOutperforms,
+
+    /// Second market's percent change beats the first's (relative matches)
+    BOutperforms,
+
+    /// Price moves more than the threshold either way (volatility matches)
+    MovesBeyond,
+
+    /// Price stays within the threshold (volatility matches)
+    StaysWithin,
+}
+
+impl PredictionSide {
+    /// Largest serialized size (tag + TargetPrice payload)
+    pub const MAX_LEN: usize = 1 + 8;
+
+    /// Slot of a two-sided pick in `Match::side_counts`
+    pub fn side_index(self) -> Option<usize> {
+        match self {
+            PredictionSide::Higher | PredictionSide::AOutperforms | PredictionSide::MovesBeyond => Some(0),
+            PredictionSide::Lower | PredictionSide::BOutperforms | PredictionSide::StaysWithin => Some(1),
+            PredictionSide::Bucket(_) | PredictionSide::TargetPrice(_) => None,
+        }
+    }
 }
 
 // ============================================================================
@@ -582,6 +2433,225 @@ pub enum ErrorCode {
 
     #[msg("Invalid username characters")]
     InvalidUsername,
+
+    #[msg("Account is already at the current version")]
+    AccountAlreadyMigrated,
+
+    #[msg("Invalid admin delay")]
+    InvalidAdminDelay,
+
+    #[msg("ETA is earlier than the admin delay allows")]
+    InvalidTimelockEta,
+
+    #[msg("Timelock has not elapsed yet")]
+    TimelockNotElapsed,
+
+    #[msg("Invalid lobby timeout")]
+    InvalidLobbyTimeout,
+
+    #[msg("Invalid keeper reward")]
+    InvalidKeeperReward,
+
+    #[msg("Lobby timeout has not been reached")]
+    LobbyNotExpired,
+
+    #[msg("Match creator cannot leave; cancel the match instead")]
+    CreatorCannotLeave,
+
+    #[msg("Invalid match access policy")]
+    InvalidAccessPolicy,
+
+    #[msg("Player is not invited to this match")]
+    NotInvited,
+
+    #[msg("Tournament is not in correct status")]
+    InvalidTournamentStatus,
+
+    #[msg("Invalid tournament size (4, 8 or 16 players)")]
+    InvalidTournamentSize,
+
+    #[msg("Invalid registration period")]
+    InvalidRegistrationPeriod,
+
+    #[msg("Payout shares must add up to 100%")]
+    InvalidPayoutSplit,
+
+    #[msg("Tournament registration is full")]
+    TournamentFull,
+
+    #[msg("Tournament registration is closed")]
+    RegistrationClosed,
+
+    #[msg("Player already registered")]
+    AlreadyRegistered,
+
+    #[msg("Player not in this tournament")]
+    PlayerNotInTournament,
+
+    #[msg("All bracket matches for this round already exist")]
+    RoundAlreadyScheduled,
+
+    #[msg("Bracket matches for this round are not finished")]
+    RoundNotFinished,
+
+    #[msg("Account does not belong to this bracket")]
+    InvalidBracketAccount,
+
+    #[msg("Tournament matches are managed by the tournament")]
+    TournamentMatch,
+
+    #[msg("Invalid price buckets")]
+    InvalidPriceBuckets,
+
+    #[msg("Prediction does not fit this match")]
+    InvalidPrediction,
+
+    #[msg("Invalid payout tiers")]
+    InvalidPayoutTiers,
+
+    #[msg("Sealed matches take committed predictions")]
+    SealedPredictionRequired,
+
+    #[msg("Match does not take sealed predictions")]
+    NotSealedMatch,
+
+    #[msg("Reveal window is not open")]
+    RevealNotOpen,
+
+    #[msg("No commitment to reveal")]
+    NoCommitment,
+
+    #[msg("Revealed prediction does not match commitment")]
+    CommitmentMismatch,
+
+    #[msg("Invalid or missing second market")]
+    InvalidSecondMarket,
+
+    #[msg("Invalid volatility threshold")]
+    InvalidVolatilityThreshold,
+
+    #[msg("Invalid elimination rounds")]
+    InvalidEliminationRounds,
+
+    #[msg("Player has been eliminated")]
+    PlayerEliminated,
+
+    #[msg("Round is not over yet")]
+    RoundNotOver,
+
+    #[msg("Elimination matches settle through rounds")]
+    EliminationMatch,
+
+    #[msg("Invalid series length (odd, 3 to 7)")]
+    InvalidSeriesLength,
+
+    #[msg("Series is not in correct status")]
+    InvalidSeriesStatus,
+
+    #[msg("A series round is already in play")]
+    SeriesRoundPending,
+
+    #[msg("Series matches are managed by the series")]
+    SeriesMatch,
+
+    #[msg("Player is not in this series")]
+    NotSeriesPlayer,
+
+    #[msg("Invalid match template")]
+    InvalidMatchTemplate,
+
+    #[msg("Template is not due to spawn yet")]
+    TemplateNotDue,
+
+    #[msg("No house bonus to reclaim")]
+    NoHouseBonus,
+
+    #[msg("Mint is not accepted here")]
+    InvalidMint,
+
+    #[msg("Missing or invalid token account")]
+    InvalidTokenAccount,
+
+    #[msg("USD entry fees are not available")]
+    UsdPricingUnavailable,
+
+    #[msg("Converted entry fee exceeds the maximum lamports")]
+    EntryFeeSlippageExceeded,
+
+    #[msg("Invalid market limits")]
+    InvalidMarketLimits,
+
+    #[msg("Match type not offered on this market")]
+    MatchTypeNotAllowed,
+
+    #[msg("Invalid fee tier")]
+    InvalidFeeTier,
+
+    #[msg("Invalid referral share (max 50%)")]
+    InvalidReferralShare,
+
+    #[msg("Players cannot refer themselves")]
+    SelfReferral,
+
+    #[msg("Referrer already set")]
+    ReferrerAlreadySet,
+
+    #[msg("Missing or invalid referrer account")]
+    InvalidReferrer,
+
+    #[msg("No referral fees to claim")]
+    NoReferralFees,
+
+    #[msg("Creator fee exceeds the allowed maximum")]
+    InvalidCreatorFee,
+
+    #[msg("Missing or invalid creator account")]
+    InvalidCreatorAccount,
+
+    #[msg("Fee recipient shares exceed 100%")]
+    InvalidFeeSplit,
+
+    #[msg("Missing or invalid fee vault")]
+    InvalidFeeVault,
+
+    #[msg("Missing or invalid fee recipient account")]
+    InvalidFeeRecipient,
+
+    #[msg("No fees to distribute")]
+    NoFeesToDistribute,
+
+    #[msg("Insurance amount must be greater than zero")]
+    InvalidInsuranceAmount,
+
+    #[msg("Cover exceeds the match vault's shortfall")]
+    InvalidShortfall,
+
+    #[msg("Shortfall reason must be 1-200 bytes")]
+    InvalidShortfallReason,
+
+    #[msg("Insurance fund balance too low")]
+    InsufficientInsuranceFunds,
+
+    #[msg("Invalid jackpot payout (max 100%)")]
+    InvalidJackpotPayout,
+
+    #[msg("Missing or invalid jackpot account")]
+    InvalidJackpot,
+
+    #[msg("No jackpot award to claim")]
+    NoJackpotAward,
+
+    #[msg("Jackpot balance too low to pay the award")]
+    InsufficientJackpotFunds,
+
+    #[msg("Match vault already holds everything still owed")]
+    NoShortfall,
+
+    #[msg("Player's user profile is required to settle this entry")]
+    InvalidUserProfile,
+
+    #[msg("Every player entry of the match is required")]
+    IncompletePlayerEntries,
 }
 
 // ============================================================================
@@ -596,6 +2666,14 @@ pub mod seeds {
     pub const PLAYER_ENTRY: &[u8] = b"player-entry";
     pub const USER_PROFILE: &[u8] = b"user-profile";
     pub const VAULT: &[u8] = b"vault";
+    pub const ROLE: &[u8] = b"role";
+    pub const QUEUED_ACTION: &[u8] = b"queued-action";
+    pub const TOURNAMENT: &[u8] = b"tournament";
+    pub const SERIES: &[u8] = b"series";
+    pub const REFERRER: &[u8] = b"referrer";
+    pub const FEE_VAULT: &[u8] = b"fee-vault";
+    pub const INSURANCE_FUND: &[u8] = b"insurance-fund";
+    pub const JACKPOT: &[u8] = b"jackpot";
 }
 
 /// Game constants
@@ -624,6 +2702,60 @@ pub mod constants {
     /// Maximum players for any match
     pub const MAX_PLAYERS: u8 = 10;
 
+    /// Maximum invited wallets on a private match (everyone but the creator)
+    pub const MAX_ALLOWLIST_PLAYERS: usize = MAX_PLAYERS as usize - 1;
+
+    /// Maximum passphrase length for private matches
+    pub const MAX_PASSPHRASE_LEN: usize = 64;
+
+    /// Maximum price-change buckets for range predictions
+    pub const MAX_PRICE_BUCKETS: usize = 8;
+
+    /// Minimum price-change buckets for range predictions
+    pub const MIN_PRICE_BUCKETS: usize = 3;
+
+    /// Minimum move threshold for volatility matches (0.01%)
+    pub const MIN_VOLATILITY_THRESHOLD_BPS: u16 = 1;
+
+    /// Maximum move threshold for volatility matches (50%)
+    pub const MAX_VOLATILITY_THRESHOLD_BPS: u16 = 5000;
+
+    /// Maximum rounds in an elimination BattleRoyale
+    pub const MAX_ELIMINATION_ROUNDS: u8 = 5;
+
+    /// Minimum rounds in an elimination BattleRoyale
+    pub const MIN_ELIMINATION_ROUNDS: u8 = 2;
+
+    /// Longest best-of-N series
+    pub const MAX_SERIES_LENGTH: u8 = 7;
+
+    /// Round matches allowed per series round before an undecided series is cancelled
+    pub const SERIES_REPLAY_FACTOR: u8 = 2;
+
+    /// Minimum USD-pegged entry fee in cents ($1)
+    pub const MIN_ENTRY_FEE_USD_CENTS: u64 = 100;
+
+    /// Maximum USD-pegged entry fee in cents ($10,000)
+    pub const MAX_ENTRY_FEE_USD_CENTS: u64 = 1_000_000;
+
+    /// Maximum match templates per market
+    pub const MAX_MARKET_TEMPLATES: usize = 4;
+
+    /// Shortest interval between template spawns
+    pub const MIN_TEMPLATE_CADENCE: i64 = 60;
+
+    /// Maximum payout tiers for closest-price matches
+    pub const MAX_PAYOUT_TIERS: usize = 3;
+
+    /// Minimum tournament bracket size
+    pub const MIN_TOURNAMENT_PLAYERS: u8 = 4;
+
+    /// Maximum tournament bracket size
+    pub const MAX_TOURNAMENT_PLAYERS: usize = 16;
+
+    /// Maximum tournament registration period (7 days)
+    pub const MAX_REGISTRATION_PERIOD: i64 = 7 * 24 * 60 * 60;
+
     /// Pyth price staleness threshold (60 seconds)
     pub const PYTH_STALENESS_THRESHOLD: i64 = 60;
 
@@ -635,4 +2767,46 @@ pub mod constants {
 
     /// Basis points denominator
     pub const BPS_DENOMINATOR: u16 = 10000;
+
+    /// Default delay before queued config changes execute (48 hours)
+    pub const DEFAULT_ADMIN_DELAY: i64 = 48 * 60 * 60;
+
+    /// Minimum delay before queued config changes execute (1 hour)
+    pub const MIN_ADMIN_DELAY: i64 = 60 * 60;
+
+    /// Maximum delay before queued config changes execute (30 days)
+    pub const MAX_ADMIN_DELAY: i64 = 30 * 24 * 60 * 60;
+
+    /// Default time an Open match waits for players (24 hours)
+    pub const DEFAULT_LOBBY_TIMEOUT: i64 = 24 * 60 * 60;
+
+    /// Minimum lobby timeout (5 minutes)
+    pub const MIN_LOBBY_TIMEOUT: i64 = 5 * 60;
+
+    /// Maximum lobby timeout (7 days)
+    pub const MAX_LOBBY_TIMEOUT: i64 = 7 * 24 * 60 * 60;
+
+    /// Maximum keeper reward for expiring a match (0.01 SOL)
+    pub const MAX_KEEPER_REWARD: u64 = 10_000_000;
+
+    /// Fee discount tiers in the config
+    pub const MAX_FEE_TIERS: usize = 3;
+
+    /// Maximum referrer share of a referred player's protocol fee (50%)
+    pub const MAX_REFERRAL_SHARE_BPS: u16 = 5000;
+
+    /// Hard cap on the configurable creator fee cap (10%)
+    pub const MAX_CREATOR_FEE_BPS: u16 = 1000;
+
+    /// Fee recipient slots in the fee vault
+    pub const MAX_FEE_RECIPIENTS: usize = 4;
+
+    /// Longest reason logged with a shortfall cover
+    pub const MAX_SHORTFALL_REASON_LEN: usize = 200;
+
+    /// Win streak that hits the jackpot
+    pub const JACKPOT_STREAK: u16 = 10;
+
+    /// Length of a rolling-volume epoch (30 days)
+    pub const VOLUME_EPOCH_SECONDS: i64 = 30 * 24 * 60 * 60;
 }
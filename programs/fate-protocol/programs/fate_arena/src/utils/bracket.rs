@@ -0,0 +1,136 @@
+use crate::PredictionSide;
+
+/// Bracket positions for seeds 0..size so that the top seeds meet last
+/// (e.g. size 8 => [0, 7, 3, 4, 1, 6, 2, 5])
+/// `size` must be a power of two
+pub fn seeding_order(size: usize) -> Vec<usize> {
+    let mut order = vec![0usize];
+
+    while order.len() < size {
+        let next_size = order.len() * 2;
+        order = order
+            .iter()
+            .flat_map(|&seed| [seed, next_size - 1 - seed])
+            .collect();
+    }
+
+    order
+}
+
+/// Number of rounds in a single-elimination bracket of `size` players
+pub fn total_rounds(size: u8) -> u8 {
+    size.trailing_zeros() as u8
+}
+
+/// One side of a bracket pairing as recorded on its PlayerEntry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Contender {
+    /// Seed rank (0 = top seed)
+    pub seed: u8,
+
+    /// Locked prediction, if any
+    pub prediction: Option<PredictionSide>,
+
+    /// When the prediction was locked
+    pub locked_at: Option<i64>,
+}
+
+impl Contender {
+    /// Score a contender against the match result
+    /// Correct call = 2, any other call = 1, no call = 0
+    /// `resolved` is false when the match never started (nobody predicted)
+    pub fn score(&self, resolved: bool, winning_side: Option<PredictionSide>) -> u8 {
+        match (resolved, self.prediction) {
+            (false, _) | (true, None) => 0,
+            (true, Some(side)) if winning_side == Some(side) => 2,
+            (true, Some(_)) => 1,
+        }
+    }
+}
+
+/// Decide a bracket pairing: higher score wins, then the earlier
+/// prediction lock time, then the better seed
+/// Returns true if `a` advances
+pub fn first_advances(
+    a: &Contender,
+    b: &Contender,
+    resolved: bool,
+    winning_side: Option<PredictionSide>,
+) -> bool {
+    let score_a = a.score(resolved, winning_side);
+    let score_b = b.score(resolved, winning_side);
+
+    if score_a != score_b {
+        return score_a > score_b;
+    }
+
+    let locked_a = a.locked_at.unwrap_or(i64::MAX);
+    let locked_b = b.locked_at.unwrap_or(i64::MAX);
+
+    if locked_a != locked_b {
+        return locked_a < locked_b;
+    }
+
+    a.seed < b.seed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contender(seed: u8, prediction: Option<PredictionSide>, locked_at: Option<i64>) -> Contender {
+        Contender { seed, prediction, locked_at }
+    }
+
+    #[test]
+    fn test_seeding_order() {
+        assert_eq!(seeding_order(2), vec![0, 1]);
+        assert_eq!(seeding_order(4), vec![0, 3, 1, 2]);
+        assert_eq!(seeding_order(8), vec![0, 7, 3, 4, 1, 6, 2, 5]);
+    }
+
+    #[test]
+    fn test_total_rounds() {
+        assert_eq!(total_rounds(4), 2);
+        assert_eq!(total_rounds(8), 3);
+        assert_eq!(total_rounds(16), 4);
+    }
+
+    #[test]
+    fn test_correct_call_advances() {
+        let a = contender(3, Some(PredictionSide::Higher), Some(200));
+        let b = contender(0, Some(PredictionSide::Lower), Some(100));
+
+        assert!(first_advances(&a, &b, true, Some(PredictionSide::Higher)));
+        assert!(!first_advances(&a, &b, true, Some(PredictionSide::Lower)));
+    }
+
+    #[test]
+    fn test_tie_breaks_on_lock_time() {
+        // Same call: earlier lock wins regardless of seed
+        let a = contender(3, Some(PredictionSide::Higher), Some(100));
+        let b = contender(0, Some(PredictionSide::Higher), Some(150));
+
+        assert!(first_advances(&a, &b, true, Some(PredictionSide::Higher)));
+        assert!(first_advances(&a, &b, true, Some(PredictionSide::Lower)));
+        // Flat price: every call scores the same
+        assert!(first_advances(&a, &b, true, None));
+    }
+
+    #[test]
+    fn test_missing_prediction_loses() {
+        let a = contender(0, None, None);
+        let b = contender(1, Some(PredictionSide::Lower), Some(120));
+
+        assert!(!first_advances(&a, &b, true, Some(PredictionSide::Higher)));
+    }
+
+    #[test]
+    fn test_unresolved_falls_back_to_seed() {
+        let a = contender(5, None, None);
+        let b = contender(2, None, None);
+
+        assert!(!first_advances(&a, &b, false, None));
+        assert!(first_advances(&b, &a, false, None));
+    }
+}
@@ -0,0 +1,48 @@
+use crate::constants::{MAX_PRICE_BUCKETS, MIN_PRICE_BUCKETS};
+
+/// Check bucket boundaries: strictly ascending, MIN..=MAX buckets
+pub fn validate_bucket_bounds(bounds_bps: &[i32]) -> bool {
+    let buckets = bounds_bps.len() + 1;
+
+    (MIN_PRICE_BUCKETS..=MAX_PRICE_BUCKETS).contains(&buckets) &&
+        bounds_bps.windows(2).all(|pair| pair[0] < pair[1])
+}
+
+/// Bucket a price change falls into
+/// Bucket 0 is below the first boundary, bucket N is at or above the last
+pub fn bucket_for_change(bounds_bps: &[i32], change_bps: i64) -> u8 {
+    bounds_bps
+        .iter()
+        .take_while(|&&bound| change_bps >= bound as i64)
+        .count() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // <-2%, -2..-0.5%, -0.5..+0.5%, +0.5..+2%, >+2%
+    const BOUNDS: [i32; 4] = [-200, -50, 50, 200];
+
+    #[test]
+    fn test_validate_bucket_bounds() {
+        assert!(validate_bucket_bounds(&BOUNDS));
+        assert!(validate_bucket_bounds(&[0, 100]));
+        assert!(!validate_bucket_bounds(&[0]));
+        assert!(!validate_bucket_bounds(&[100, -100, 200]));
+        assert!(!validate_bucket_bounds(&[0, 0, 100]));
+        assert!(!validate_bucket_bounds(&[1, 2, 3, 4, 5, 6, 7, 8]));
+    }
+
+    #[test]
+    fn test_bucket_for_change() {
+        assert_eq!(bucket_for_change(&BOUNDS, -500), 0);
+        assert_eq!(bucket_for_change(&BOUNDS, -200), 1);
+        assert_eq!(bucket_for_change(&BOUNDS, -51), 1);
+        assert_eq!(bucket_for_change(&BOUNDS, 0), 2);
+        assert_eq!(bucket_for_change(&BOUNDS, 50), 3);
+        assert_eq!(bucket_for_change(&BOUNDS, 199), 3);
+        assert_eq!(bucket_for_change(&BOUNDS, 200), 4);
+        assert_eq!(bucket_for_change(&BOUNDS, 10_000), 4);
+    }
+}
@@ -0,0 +1,105 @@
+use crate::constants::{BPS_DENOMINATOR, MAX_PAYOUT_TIERS};
+
+/// Check payout tiers: 1..=MAX tiers, non-increasing, non-zero, summing to 100%
+pub fn validate_payout_tiers(tiers_bps: &[u16]) -> bool {
+    let total: u64 = tiers_bps.iter().map(|&bps| bps as u64).sum();
+
+    (1..=MAX_PAYOUT_TIERS).contains(&tiers_bps.len()) &&
+        tiers_bps.iter().all(|&bps| bps > 0) &&
+        tiers_bps.windows(2).all(|pair| pair[0] >= pair[1]) &&
+        total == BPS_DENOMINATOR as u64
+}
+
+/// Rank submitted targets against the resolved price
+/// Returns the distance and player count of each paid tier
+/// (tier 0 = closest distance, tier 1 = next distinct distance, ...)
+pub fn rank_targets(targets: &[u64], end_price: u64, tiers: usize) -> (Vec<u64>, Vec<u8>) {
+    let mut distances: Vec<u64> = targets
+        .iter()
+        .map(|&target| target.abs_diff(end_price))
+        .collect();
+    distances.sort_unstable();
+
+    let mut tier_distances: Vec<u64> = Vec::with_capacity(tiers);
+    let mut tier_counts: Vec<u8> = Vec::with_capacity(tiers);
+
+    for distance in distances {
+        if tier_distances.last() == Some(&distance) {
+            if let Some(count) = tier_counts.last_mut() {
+                *count += 1;
+            }
+        } else if tier_distances.len() < tiers {
+            tier_distances.push(distance);
+            tier_counts.push(1);
+        } else {
+            break;
+        }
+    }
+
+    (tier_distances, tier_counts)
+}
+
+/// Share of `prize_pool` for a target `distance` away from the resolved price
+/// Ties split their tier; tiers nobody reached are folded into the others
+pub fn tier_payout(
+    tiers_bps: &[u16],
+    tier_distances: &[u64],
+    tier_counts: &[u8],
+    distance: u64,
+    prize_pool: u64,
+) -> u64 {
+    let Some(tier) = tier_distances.iter().position(|&d| d == distance) else {
+        return 0;
+    };
+
+    let reached_bps: u128 = tiers_bps[..tier_distances.len()]
+        .iter()
+        .map(|&bps| bps as u128)
+        .sum();
+
+    (prize_pool as u128 * tiers_bps[tier] as u128 / reached_bps / tier_counts[tier] as u128) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TIERS: [u16; 3] = [6000, 3000, 1000];
+
+    #[test]
+    fn test_validate_payout_tiers() {
+        assert!(validate_payout_tiers(&TIERS));
+        assert!(validate_payout_tiers(&[10_000]));
+        assert!(!validate_payout_tiers(&[]));
+        assert!(!validate_payout_tiers(&[3000, 7000]));
+        assert!(!validate_payout_tiers(&[5000, 4000]));
+        assert!(!validate_payout_tiers(&[10_000, 0]));
+        assert!(!validate_payout_tiers(&[4000, 3000, 2000, 1000]));
+    }
+
+    #[test]
+    fn test_rank_targets() {
+        let targets = [105, 95, 120, 100, 80, 101];
+        let (distances, counts) = rank_targets(&targets, 100, 3);
+
+        assert_eq!(distances, vec![0, 1, 5]);
+        assert_eq!(counts, vec![1, 1, 2]);
+    }
+
+    #[test]
+    fn test_tier_payout_splits_ties() {
+        let (distances, counts) = rank_targets(&[98, 102, 110], 100, 3);
+        assert_eq!(distances, vec![2, 10]);
+
+        // Two players tie for closest and split tier 0; tier 2 is folded back
+        assert_eq!(tier_payout(&TIERS, &distances, &counts, 2, 900), 300);
+        assert_eq!(tier_payout(&TIERS, &distances, &counts, 10, 900), 300);
+        assert_eq!(tier_payout(&TIERS, &distances, &counts, 50, 900), 0);
+    }
+
+    #[test]
+    fn test_tier_payout_single_player() {
+        let (distances, counts) = rank_targets(&[150], 100, 3);
+        assert_eq!(tier_payout(&TIERS, &distances, &counts, 50, 1_000), 1_000);
+    }
+}
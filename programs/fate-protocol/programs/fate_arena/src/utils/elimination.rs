@@ -0,0 +1,108 @@
+use crate::{EliminationRound, PredictionSide};
+
+/// Players left after a round
+/// Wrong calls and missing calls are knocked out, unless nobody still in
+/// called it right (or the price did not move), in which case everyone stays
+pub fn round_survivors(
+    alive: u16,
+    higher: u16,
+    lower: u16,
+    winning_side: Option<PredictionSide>,
+) -> u16 {
+    let correct = match winning_side {
+        Some(PredictionSide::Higher) => alive & higher,
+        Some(PredictionSide::Lower) => alive & lower,
+        _ => 0,
+    };
+
+    if correct == 0 {
+        alive
+    } else {
+        correct
+    }
+}
+
+/// Number of recorded rounds the player at `index` came through
+pub fn rounds_survived(rounds: &[EliminationRound], index: u8) -> u8 {
+    rounds
+        .iter()
+        .filter(|round| round.end_price.is_some() && round.survivors & (1 << index) != 0)
+        .count() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::prelude::Pubkey;
+    use crate::{Match, MatchStatus, MatchType};
+
+    fn round(survivors: u16) -> EliminationRound {
+        EliminationRound {
+            start_price: 100,
+            end_price: Some(101),
+            winning_side: Some(PredictionSide::Higher),
+            survivors,
+        }
+    }
+
+    #[test]
+    fn test_wrong_and_missing_calls_are_eliminated() {
+        // Players 0-4 alive; 0 and 3 call Higher, 1 and 2 call Lower, 4 sits out
+        let alive = 0b11111;
+        let higher = 0b01001;
+        let lower = 0b00110;
+
+        assert_eq!(round_survivors(alive, higher, lower, Some(PredictionSide::Higher)), 0b01001);
+        assert_eq!(round_survivors(alive, higher, lower, Some(PredictionSide::Lower)), 0b00110);
+    }
+
+    #[test]
+    fn test_everyone_stays_when_nobody_is_right() {
+        let alive = 0b0111;
+
+        assert_eq!(round_survivors(alive, 0b0111, 0, Some(PredictionSide::Lower)), alive);
+        assert_eq!(round_survivors(alive, 0b0011, 0b0100, None), alive);
+    }
+
+    #[test]
+    fn test_eliminated_players_stay_out() {
+        // Player 2 was already knocked out; their stale bit must not revive them
+        let alive = 0b0011;
+        assert_eq!(round_survivors(alive, 0b0100, 0b0011, Some(PredictionSide::Higher)), alive);
+        assert_eq!(round_survivors(alive, 0b0101, 0b0010, Some(PredictionSide::Higher)), 0b0001);
+    }
+
+    #[test]
+    fn test_flat_final_round_pays_survivors() {
+        // Players 0 and 1 came through; the last round ended flat
+        let mut match_account = Match::new(
+            1,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            MatchType::BattleRoyale,
+            255,
+            0,
+        );
+        match_account.elimination_rounds = 3;
+        match_account.alive_mask = 0b011;
+        match_account.winning_side = None;
+        match_account.status = MatchStatus::Completed;
+
+        assert!(!match_account.is_refund());
+        assert!(match_account.is_winning_entry(Some(PredictionSide::Higher), 0));
+        assert!(match_account.is_winning_entry(None, 1));
+        assert!(!match_account.is_winning_entry(Some(PredictionSide::Lower), 2));
+    }
+
+    #[test]
+    fn test_rounds_survived() {
+        let mut rounds = vec![round(0b111), round(0b101), round(0b001)];
+        assert_eq!(rounds_survived(&rounds, 0), 3);
+        assert_eq!(rounds_survived(&rounds, 1), 1);
+        assert_eq!(rounds_survived(&rounds, 2), 2);
+
+        // A round still in play doesn't count yet
+        rounds.push(EliminationRound { end_price: None, ..round(0b001) });
+        assert_eq!(rounds_survived(&rounds, 0), 3);
+    }
+}
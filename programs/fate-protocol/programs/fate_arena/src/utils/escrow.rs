@@ -0,0 +1,310 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+    onchain::invoke_transfer_checked,
+};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::{Match, ErrorCode, seeds};
+
+/// Token-2022 mint extensions a match can escrow safely
+/// Anything else (non-transferable, permanent delegate, default-frozen
+/// accounts, confidential transfers, ...) could lock or drain the vault
+pub const SUPPORTED_MINT_EXTENSIONS: [ExtensionType; 5] = [
+    ExtensionType::TransferFeeConfig,
+    ExtensionType::TransferHook,
+    ExtensionType::MintCloseAuthority,
+    ExtensionType::MetadataPointer,
+    ExtensionType::TokenMetadata,
+];
+
+/// Check that a Token-2022 mint only uses supported extensions
+/// Legacy SPL Token mints carry no extensions and always pass
+pub fn validate_mint_extensions(mint_data: &[u8]) -> Result<()> {
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(mint_data)
+        .map_err(|_| ErrorCode::InvalidMint)?;
+    let extensions = mint.get_extension_types()
+        .map_err(|_| ErrorCode::InvalidMint)?;
+
+    require!(
+        extensions.iter().all(|extension| SUPPORTED_MINT_EXTENSIONS.contains(extension)),
+        ErrorCode::InvalidMint
+    );
+
+    Ok(())
+}
+
+/// Where a match keeps its stakes: lamports in the `["vault", match]` PDA, or
+/// for token matches the match PDA's associated token account for its mint
+pub struct Escrow<'info> {
+    match_id: u64,
+    match_bump: u8,
+    match_info: AccountInfo<'info>,
+    vault: AccountInfo<'info>,
+    vault_bump: u8,
+    token: Option<TokenEscrow<'info>>,
+    system_program: AccountInfo<'info>,
+}
+
+/// Token side of a match escrow
+struct TokenEscrow<'info> {
+    mint: AccountInfo<'info>,
+    decimals: u8,
+    vault: AccountInfo<'info>,
+    program: AccountInfo<'info>,
+    /// Accounts a transfer-hook mint needs, picked by key at transfer time
+    hook_accounts: Vec<AccountInfo<'info>>,
+}
+
+impl<'info> Escrow<'info> {
+    /// Collect the escrow accounts for `match_account`, checking that token
+    /// matches pass their mint, vault and token program
+    pub fn new(
+        match_account: &Account<'info, Match>,
+        vault: &AccountInfo<'info>,
+        vault_bump: u8,
+        mint: Option<&InterfaceAccount<'info, Mint>>,
+        token_vault: Option<&InterfaceAccount<'info, TokenAccount>>,
+        token_program: Option<&Interface<'info, TokenInterface>>,
+        system_program: &Program<'info, System>,
+    ) -> Result<Self> {
+        let token = match match_account.mint {
+            None => None,
+            Some(expected_mint) => {
+                let mint = mint.ok_or(ErrorCode::InvalidTokenAccount)?;
+                require_keys_eq!(mint.key(), expected_mint, ErrorCode::InvalidMint);
+                let token_program = token_program.ok_or(ErrorCode::InvalidTokenAccount)?;
+                let token_vault = token_vault.ok_or(ErrorCode::InvalidTokenAccount)?;
+                require_keys_eq!(
+                    token_vault.key(),
+                    get_associated_token_address_with_program_id(
+                        &match_account.key(),
+                        &expected_mint,
+                        &token_program.key()
+                    ),
+                    ErrorCode::InvalidTokenAccount
+                );
+
+                Some(TokenEscrow {
+                    mint: mint.to_account_info(),
+                    decimals: mint.decimals,
+                    vault: token_vault.to_account_info(),
+                    program: token_program.to_account_info(),
+                    hook_accounts: Vec::new(),
+                })
+            }
+        };
+
+        Ok(Self {
+            match_id: match_account.match_id,
+            match_bump: match_account.bump,
+            match_info: match_account.to_account_info(),
+            vault: vault.clone(),
+            vault_bump,
+            token,
+            system_program: system_program.to_account_info(),
+        })
+    }
+
+    /// Make `accounts` available to a transfer-hook mint (e.g. the
+    /// instruction's remaining accounts); unrelated accounts are ignored
+    pub fn with_hook_accounts(mut self, accounts: &[AccountInfo<'info>]) -> Self {
+        if let Some(token) = self.token.as_mut() {
+            token.hook_accounts = accounts.to_vec();
+        }
+        self
+    }
+
+    /// Pull `amount` from `owner` into escrow
+    /// Token matches take it from `owner_tokens`, which `owner` must sign for
+    /// Returns the amount the vault actually received, which is less than
+    /// `amount` for mints with a transfer fee
+    pub fn deposit(
+        &self,
+        owner: &AccountInfo<'info>,
+        owner_tokens: Option<&InterfaceAccount<'info, TokenAccount>>,
+        amount: u64,
+    ) -> Result<u64> {
+        match &self.token {
+            Some(token) => {
+                let owner_tokens = self.checked_token_account(owner.key, owner_tokens)?;
+                let before = self.balance()?;
+
+                invoke_transfer_checked(
+                    token.program.key,
+                    owner_tokens,
+                    token.mint.clone(),
+                    token.vault.clone(),
+                    owner.clone(),
+                    &token.hook_accounts,
+                    amount,
+                    token.decimals,
+                    &[],
+                )?;
+
+                let received = self.balance()?.checked_sub(before)
+                    .ok_or(ErrorCode::ArithmeticUnderflow)?;
+                Ok(received)
+            }
+            None => {
+                system_program::transfer(
+                    CpiContext::new(
+                        self.system_program.clone(),
+                        system_program::Transfer {
+                            from: owner.clone(),
+                            to: self.vault.clone(),
+                        },
+                    ),
+                    amount,
+                )?;
+                Ok(amount)
+            }
+        }
+    }
+
+    /// Pay `amount` out of escrow to `recipient`
+    /// Token matches pay into `recipient_tokens`, which must be owned by `recipient`
+    pub fn pay(
+        &self,
+        recipient: &AccountInfo<'info>,
+        recipient_tokens: Option<&InterfaceAccount<'info, TokenAccount>>,
+        amount: u64,
+    ) -> Result<()> {
+        match &self.token {
+            Some(token) => {
+                let recipient_tokens = self.checked_token_account(recipient.key, recipient_tokens)?;
+                let match_id = self.match_id.to_le_bytes();
+                let match_seeds = &[
+                    seeds::MATCH,
+                    match_id.as_ref(),
+                    &[self.match_bump],
+                ];
+                let signer_seeds = &[&match_seeds[..]];
+
+                invoke_transfer_checked(
+                    token.program.key,
+                    token.vault.clone(),
+                    token.mint.clone(),
+                    recipient_tokens,
+                    self.match_info.clone(),
+                    &token.hook_accounts,
+                    amount,
+                    token.decimals,
+                    signer_seeds,
+                )?;
+                Ok(())
+            }
+            None => {
+                let match_key = self.match_info.key();
+                let vault_seeds = &[
+                    seeds::VAULT,
+                    match_key.as_ref(),
+                    &[self.vault_bump],
+                ];
+                let signer_seeds = &[&vault_seeds[..]];
+
+                system_program::transfer(
+                    CpiContext::new_with_signer(
+                        self.system_program.clone(),
+                        system_program::Transfer {
+                            from: self.vault.clone(),
+                            to: recipient.clone(),
+                        },
+                        signer_seeds,
+                    ),
+                    amount,
+                )
+            }
+        }
+    }
+
+    /// Amount currently held in escrow (lamports or token base units)
+    pub fn balance(&self) -> Result<u64> {
+        match &self.token {
+            Some(token) => {
+                let data = token.vault.try_borrow_data()?;
+                Ok(TokenAccount::try_deserialize(&mut &data[..])?.amount)
+            }
+            None => Ok(self.vault.lamports()),
+        }
+    }
+
+    fn checked_token_account(
+        &self,
+        owner: &Pubkey,
+        tokens: Option<&InterfaceAccount<'info, TokenAccount>>,
+    ) -> Result<AccountInfo<'info>> {
+        let tokens = tokens.ok_or(ErrorCode::InvalidTokenAccount)?;
+        let mint = self.token.as_ref().map(|token| token.mint.key());
+        require!(
+            tokens.owner == *owner && Some(tokens.mint) == mint,
+            ErrorCode::InvalidTokenAccount
+        );
+        Ok(tokens.to_account_info())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_spl::token_2022::spl_token_2022::{
+        extension::{
+            non_transferable::NonTransferable, transfer_fee::TransferFeeConfig,
+            transfer_hook::TransferHook, StateWithExtensionsMut,
+        },
+        state::Mint as MintState,
+    };
+    use anchor_lang::solana_program::program_pack::Pack;
+
+    fn mint_with(extensions: &[ExtensionType], init: impl FnOnce(&mut StateWithExtensionsMut<MintState>)) -> Vec<u8> {
+        let len = ExtensionType::try_calculate_account_len::<MintState>(extensions).unwrap();
+        let mut data = vec![0u8; len];
+        let mut mint = StateWithExtensionsMut::<MintState>::unpack_uninitialized(&mut data).unwrap();
+        init(&mut mint);
+        mint.base = MintState {
+            decimals: 6,
+            is_initialized: true,
+            ..Default::default()
+        };
+        mint.pack_base();
+        if !extensions.is_empty() {
+            mint.init_account_type().unwrap();
+        }
+        data
+    }
+
+    #[test]
+    fn test_legacy_mint_is_supported() {
+        let mut data = vec![0u8; MintState::LEN];
+        MintState::pack(
+            MintState { decimals: 6, is_initialized: true, ..Default::default() },
+            &mut data,
+        ).unwrap();
+
+        assert!(validate_mint_extensions(&data).is_ok());
+    }
+
+    #[test]
+    fn test_transfer_fee_and_hook_are_supported() {
+        let data = mint_with(
+            &[ExtensionType::TransferFeeConfig, ExtensionType::TransferHook],
+            |mint| {
+                mint.init_extension::<TransferFeeConfig>(true).unwrap();
+                mint.init_extension::<TransferHook>(true).unwrap();
+            },
+        );
+
+        assert!(validate_mint_extensions(&data).is_ok());
+    }
+
+    #[test]
+    fn test_non_transferable_is_rejected() {
+        let data = mint_with(&[ExtensionType::NonTransferable], |mint| {
+            mint.init_extension::<NonTransferable>(true).unwrap();
+        });
+
+        assert!(validate_mint_extensions(&data).is_err());
+    }
+}
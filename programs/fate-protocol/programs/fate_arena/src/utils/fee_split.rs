@@ -0,0 +1,40 @@
+use crate::constants::BPS_DENOMINATOR;
+
+/// Split `amount` between recipients by bps share
+/// Returns each recipient's amount and the remainder left for the treasury
+/// (unallocated bps plus rounding dust)
+pub fn split_fees(amount: u64, shares_bps: &[u16]) -> (Vec<u64>, u64) {
+    let shares: Vec<u64> = shares_bps.iter()
+        .map(|&bps| (amount as u128 * bps as u128 / BPS_DENOMINATOR as u128) as u64)
+        .collect();
+    let remainder = amount.saturating_sub(shares.iter().sum());
+    (shares, remainder)
+}
+
+/// Check that shares fit in 100%
+pub fn validate_fee_split(shares_bps: &[u16]) -> bool {
+    shares_bps.iter().map(|&bps| bps as u32).sum::<u32>() <= BPS_DENOMINATOR as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_fees() {
+        // 50% ops, 30% council, rest to treasury
+        assert_eq!(split_fees(1_000, &[5_000, 3_000]), (vec![500, 300], 200));
+        // Rounding dust goes to the treasury
+        assert_eq!(split_fees(101, &[5_000, 5_000]), (vec![50, 50], 1));
+        assert_eq!(split_fees(1_000, &[]), (vec![], 1_000));
+        assert_eq!(split_fees(0, &[10_000]), (vec![0], 0));
+    }
+
+    #[test]
+    fn test_validate_fee_split() {
+        assert!(validate_fee_split(&[5_000, 5_000]));
+        assert!(validate_fee_split(&[]));
+        assert!(!validate_fee_split(&[5_000, 5_001]));
+        assert!(!validate_fee_split(&[u16::MAX, u16::MAX]));
+    }
+}
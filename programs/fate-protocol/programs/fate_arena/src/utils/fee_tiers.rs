@@ -0,0 +1,92 @@
+use crate::{FeeTier, constants::BPS_DENOMINATOR};
+
+/// Discount of the best tier a player qualifies for, by level or by volume
+/// A zero threshold disables that path; a tier with no discount is unused
+pub fn tier_discount_bps(tiers: &[FeeTier], level: u16, volume: u64) -> u16 {
+    tiers.iter()
+        .filter(|tier| {
+            (tier.min_level > 0 && level >= tier.min_level) ||
+            (tier.min_volume > 0 && volume >= tier.min_volume)
+        })
+        .map(|tier| tier.discount_bps.min(BPS_DENOMINATOR))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Fee in bps left after a discount, rounded down in the player's favour
+pub fn discounted_fee_bps(fee_bps: u16, discount_bps: u16) -> u16 {
+    let discount_bps = discount_bps.min(BPS_DENOMINATOR);
+    (fee_bps as u32 * (BPS_DENOMINATOR - discount_bps) as u32 / BPS_DENOMINATOR as u32) as u16
+}
+
+/// Volume over the recorded epoch and the one before it, as seen from `now_epoch`
+/// Epochs older than that no longer count
+pub fn rolling_volume(epoch: u32, epoch_volume: u64, prev_epoch_volume: u64, now_epoch: u32) -> u64 {
+    match now_epoch.saturating_sub(epoch) {
+        0 => epoch_volume.saturating_add(prev_epoch_volume),
+        1 => epoch_volume,
+        _ => 0,
+    }
+}
+
+/// Add `amount` to the volume of `now_epoch`, rolling the epochs forward first
+/// Returns the new (epoch, epoch_volume, prev_epoch_volume)
+pub fn record_volume(
+    epoch: u32,
+    epoch_volume: u64,
+    prev_epoch_volume: u64,
+    now_epoch: u32,
+    amount: u64,
+) -> (u32, u64, u64) {
+    let (current, previous) = match now_epoch.saturating_sub(epoch) {
+        0 => (epoch_volume, prev_epoch_volume),
+        1 => (0, epoch_volume),
+        _ => (0, 0),
+    };
+    (now_epoch.max(epoch), current.saturating_add(amount), previous)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tier(min_level: u16, min_volume: u64, discount_bps: u16) -> FeeTier {
+        FeeTier { min_level, min_volume, discount_bps }
+    }
+
+    #[test]
+    fn test_tier_discount_bps() {
+        let tiers = [tier(5, 0, 1_000), tier(10, 100_000_000_000, 2_500), tier(0, 0, 0)];
+
+        assert_eq!(tier_discount_bps(&tiers, 1, 0), 0);
+        assert_eq!(tier_discount_bps(&tiers, 5, 0), 1_000);
+        // Either threshold qualifies; the best discount wins
+        assert_eq!(tier_discount_bps(&tiers, 5, 100_000_000_000), 2_500);
+        assert_eq!(tier_discount_bps(&tiers, 12, 0), 2_500);
+        assert_eq!(tier_discount_bps(&[], 50, u64::MAX), 0);
+    }
+
+    #[test]
+    fn test_discounted_fee_bps() {
+        assert_eq!(discounted_fee_bps(300, 0), 300);
+        assert_eq!(discounted_fee_bps(300, 2_500), 225);
+        assert_eq!(discounted_fee_bps(300, 10_000), 0);
+        assert_eq!(discounted_fee_bps(300, u16::MAX), 0);
+    }
+
+    #[test]
+    fn test_rolling_volume() {
+        assert_eq!(rolling_volume(10, 5, 3, 10), 8);
+        assert_eq!(rolling_volume(10, 5, 3, 11), 5);
+        assert_eq!(rolling_volume(10, 5, 3, 12), 0);
+    }
+
+    #[test]
+    fn test_record_volume() {
+        assert_eq!(record_volume(10, 5, 3, 10, 2), (10, 7, 3));
+        assert_eq!(record_volume(10, 5, 3, 11, 2), (11, 2, 5));
+        assert_eq!(record_volume(10, 5, 3, 14, 2), (14, 2, 0));
+        // A fresh profile starts at epoch 0
+        assert_eq!(record_volume(0, 0, 0, 700, 2), (700, 2, 0));
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{JackpotTrigger, PredictionSide, constants::{BPS_DENOMINATOR, JACKPOT_STREAK}};
+
+/// Rare outcome of a winning claim that pays the jackpot, if any
+/// A streak only counts once the player has no other entries left unsettled,
+/// so no loss can be held back by not claiming it; an exact hit is a
+/// closest-price target equal to the end price
+pub fn jackpot_trigger(
+    jackpot_streak: u16,
+    open_entries: u16,
+    prediction: Option<PredictionSide>,
+    end_price: Option<u64>,
+) -> Option<JackpotTrigger> {
+    if jackpot_streak >= JACKPOT_STREAK && open_entries == 0 {
+        return Some(JackpotTrigger::WinStreak);
+    }
+    match (prediction, end_price) {
+        (Some(PredictionSide::TargetPrice(target)), Some(end_price)) if target == end_price => {
+            Some(JackpotTrigger::ExactPrice)
+        },
+        _ => None,
+    }
+}
+
+/// Jackpot streak after a win or loss of a match resolved at `resolved_at`
+/// The streak follows resolution order, not claim order: a result older than
+/// the last one counted breaks it, so reordering claims can't skip a loss
+pub fn next_jackpot_streak(streak: u16, last_settled_at: i64, won: bool, resolved_at: i64) -> u16 {
+    if won && resolved_at >= last_settled_at {
+        streak.saturating_add(1)
+    } else {
+        0
+    }
+}
+
+/// Award for a hit: `payout_bps` of the jackpot not yet promised to earlier hits
+pub fn jackpot_award(available: u64, payout_bps: u16) -> u64 {
+    let payout_bps = payout_bps.min(BPS_DENOMINATOR);
+    (available as u128 * payout_bps as u128 / BPS_DENOMINATOR as u128) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Jackpot, ErrorCode};
+
+    #[test]
+    fn test_jackpot_trigger() {
+        let higher = Some(PredictionSide::Higher);
+        assert_eq!(jackpot_trigger(9, 0, higher, Some(100)), None);
+        assert_eq!(jackpot_trigger(10, 0, higher, Some(100)), Some(JackpotTrigger::WinStreak));
+        assert_eq!(jackpot_trigger(12, 0, higher, Some(100)), Some(JackpotTrigger::WinStreak));
+        // Unsettled entries could hide a loss
+        assert_eq!(jackpot_trigger(10, 1, higher, Some(100)), None);
+
+        let target = Some(PredictionSide::TargetPrice(100));
+        assert_eq!(jackpot_trigger(0, 3, target, Some(100)), Some(JackpotTrigger::ExactPrice));
+        assert_eq!(jackpot_trigger(0, 0, target, Some(101)), None);
+        assert_eq!(jackpot_trigger(0, 0, target, None), None);
+        assert_eq!(jackpot_trigger(0, 0, None, Some(100)), None);
+    }
+
+    #[test]
+    fn test_jackpot_streak_follows_resolution_order() {
+        // Wins resolved at 1..=3 and a loss at 4, claimed in order
+        let mut streak = 0;
+        let mut last = 0;
+        for (won, resolved_at) in [(true, 1), (true, 2), (true, 3), (false, 4)] {
+            streak = next_jackpot_streak(streak, last, won, resolved_at);
+            last = last.max(resolved_at);
+        }
+        assert_eq!(streak, 0);
+
+        // Claiming the loss first doesn't let the earlier wins count
+        let mut streak = 0;
+        let mut last = 0;
+        for (won, resolved_at) in [(false, 4), (true, 1), (true, 2), (true, 3)] {
+            streak = next_jackpot_streak(streak, last, won, resolved_at);
+            last = last.max(resolved_at);
+        }
+        assert_eq!(streak, 0);
+
+        // Wins after the loss count either way
+        assert_eq!(next_jackpot_streak(0, 4, true, 5), 1);
+        assert_eq!(next_jackpot_streak(1, 5, true, 5), 2);
+    }
+
+    #[test]
+    fn test_jackpot_award() {
+        assert_eq!(jackpot_award(1_000_000, 10_000), 1_000_000);
+        assert_eq!(jackpot_award(1_000_000, 5_000), 500_000);
+        assert_eq!(jackpot_award(999, 5_000), 499);
+        assert_eq!(jackpot_award(1_000_000, 0), 0);
+        assert_eq!(jackpot_award(1_000_000, u16::MAX), 1_000_000);
+    }
+
+    #[test]
+    fn test_jackpot_award_and_claim_accounting() {
+        let mut jackpot = Jackpot {
+            total_funded: 1_000_000,
+            total_awarded: 0,
+            total_claimed: 0,
+            hits: 0,
+            bump: 255,
+            version: Jackpot::VERSION,
+            reserved: [0; 64],
+        };
+        let mut balance = 1_000_000;
+
+        // Two hits at 50%: the second only sees what the first left unreserved
+        let first = jackpot_award(jackpot.unreserved(balance), 5_000);
+        jackpot.record_award(first).unwrap();
+        let second = jackpot_award(jackpot.unreserved(balance), 5_000);
+        jackpot.record_award(second).unwrap();
+        assert_eq!((first, second), (500_000, 250_000));
+        assert_eq!(jackpot.hits, 2);
+        assert_eq!(jackpot.outstanding(), 750_000);
+        assert_eq!(jackpot.unreserved(balance), 250_000);
+
+        jackpot.record_claim(first, balance).unwrap();
+        balance -= first;
+        assert_eq!(jackpot.total_claimed, 500_000);
+        assert_eq!(jackpot.outstanding(), 250_000);
+        // Claims leave the unreserved part untouched
+        assert_eq!(jackpot.unreserved(balance), 250_000);
+
+        // A balance that lost funds cannot pay a reserved award
+        assert_eq!(
+            jackpot.record_claim(second, second - 1).unwrap_err(),
+            ErrorCode::InsufficientJackpotFunds.into()
+        );
+        // Nothing beyond the outstanding awards can be claimed
+        assert_eq!(
+            jackpot.record_claim(second + 1, balance).unwrap_err(),
+            ErrorCode::NoJackpotAward.into()
+        );
+
+        jackpot.record_claim(second, balance).unwrap();
+        assert_eq!(jackpot.total_awarded, jackpot.total_claimed);
+        assert_eq!(jackpot.outstanding(), 0);
+    }
+}
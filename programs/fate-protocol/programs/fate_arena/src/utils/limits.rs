@@ -0,0 +1,39 @@
+use std::ops::RangeInclusive;
+
+/// Bounds with per-market overrides applied (0 = use the protocol-wide bound)
+pub fn override_range<T>(min_override: T, max_override: T, min: T, max: T) -> RangeInclusive<T>
+where
+    T: Copy + Default + PartialEq,
+{
+    let pick = |value: T, fallback: T| if value == T::default() { fallback } else { value };
+    pick(min_override, min)..=pick(max_override, max)
+}
+
+/// Check that an overridden range is non-empty and stays within `outer`
+pub fn within<T: PartialOrd>(range: &RangeInclusive<T>, outer: &RangeInclusive<T>) -> bool {
+    range.start() <= range.end() &&
+        outer.contains(range.start()) &&
+        outer.contains(range.end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_override_range() {
+        assert_eq!(override_range(0u64, 0, 10, 100), 10..=100);
+        assert_eq!(override_range(50u64, 0, 10, 100), 50..=100);
+        assert_eq!(override_range(0i64, 60, 30, 3600), 30..=60);
+    }
+
+    #[test]
+    fn test_within() {
+        assert!(within(&(50..=80), &(10..=100)));
+        assert!(within(&(10..=100), &(10..=100)));
+        // Inverted overrides leave no valid value
+        assert!(!within(&RangeInclusive::new(80, 50), &(10..=100)));
+        assert!(!within(&(5..=80), &(10..=100)));
+        assert!(!within(&(50..=200), &(10..=100)));
+    }
+}
@@ -0,0 +1,575 @@
+use anchor_lang::prelude::*;
+use anchor_lang::{system_program, Discriminator};
+use crate::{
+    GameConfig, Market, Match, PlayerEntry, UserProfile, ErrorCode
+};
+
+/// Program accounts that carry a layout version and can be upgraded in place.
+///
+/// New fields are appended after `version` and carved out of `reserved`, so an
+/// account written with an older layout always deserializes once its data has
+/// been zero-extended to `SPACE` bytes.
+pub trait Versioned: AccountSerialize + AccountDeserialize + Owner + Discriminator {
+    /// Allocated size of the current layout (including discriminator)
+    const SPACE: usize;
+
+    /// Current layout version
+    const CURRENT_VERSION: u8;
+
+    fn version(&self) -> u8;
+
+    fn set_version(&mut self, version: u8);
+}
+
+macro_rules! impl_versioned {
+    ($($account:ty),* $(,)?) => {
+        $(
+            impl Versioned for $account {
+                const SPACE: usize = <$account>::LEN;
+                const CURRENT_VERSION: u8 = <$account>::VERSION;
+
+                fn version(&self) -> u8 {
+                    self.version
+                }
+
+                fn set_version(&mut self, version: u8) {
+                    self.version = version;
+                }
+            }
+        )*
+    };
+}
+
+impl_versioned!(GameConfig, Market, Match, PlayerEntry, UserProfile);
+
+/// Grow `account` to the current layout size and bump its version.
+/// The payer tops up rent for the extra space.
+/// Returns (from_version, to_version).
+pub fn migrate_account<'info, T: Versioned>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<(u8, u8)> {
+    require_keys_eq!(
+        *account.owner,
+        T::owner(),
+        anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram
+    );
+
+    {
+        let data = account.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == T::DISCRIMINATOR,
+            anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+        );
+    }
+
+    if account.data_len() < T::SPACE {
+        let required_lamports = Rent::get()?.minimum_balance(T::SPACE);
+        let top_up = required_lamports.saturating_sub(account.lamports());
+
+        if top_up > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    system_program::Transfer {
+                        from: payer.clone(),
+                        to: account.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+
+        account.realloc(T::SPACE, true)?;
+    }
+
+    let mut data = account.try_borrow_mut_data()?;
+    upgrade_in_place::<T>(&mut data)
+}
+
+/// Rewrite an account buffer that is already `T::SPACE` bytes long
+/// with the current layout version.
+/// Returns (from_version, to_version).
+pub fn upgrade_in_place<T: Versioned>(data: &mut [u8]) -> Result<(u8, u8)> {
+    let mut account = T::try_deserialize(&mut &data[..])?;
+
+    let from_version = account.version();
+    require!(
+        from_version < T::CURRENT_VERSION,
+        ErrorCode::AccountAlreadyMigrated
+    );
+
+    account.set_version(T::CURRENT_VERSION);
+
+    let mut writer: &mut [u8] = data;
+    account.try_serialize(&mut writer)?;
+
+    Ok((from_version, T::CURRENT_VERSION))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MatchAccess, MatchStatus, MatchType, PredictionSide};
+
+    /// Account layouts as written before versioning was introduced
+    mod legacy {
+        use super::*;
+
+        #[derive(AnchorSerialize)]
+        pub struct GameConfig {
+            pub authority: Pubkey,
+            pub treasury: Pubkey,
+            pub protocol_fee_bps: u16,
+            pub total_matches: u64,
+            pub total_volume: u64,
+            pub paused: bool,
+            pub bump: u8,
+        }
+
+        pub const GAME_CONFIG_LEN: usize = 8 + 32 + 32 + 2 + 8 + 8 + 1 + 1;
+
+        #[derive(AnchorSerialize)]
+        pub struct Market {
+            pub market_id: u64,
+            pub name: [u8; 32],
+            pub description: [u8; 128],
+            pub pyth_price_feed: Pubkey,
+            pub active: bool,
+            pub total_matches: u64,
+            pub created_at: i64,
+            pub bump: u8,
+        }
+
+        pub const MARKET_LEN: usize = 8 + 8 + 32 + 128 + 32 + 1 + 8 + 8 + 1;
+
+        /// Market v1: versioned with range buckets, before match templates
+        #[derive(AnchorSerialize)]
+        pub struct MarketV1 {
+            pub base: Market,
+            pub version: u8,
+            pub bucket_bounds_bps: Vec<i32>,
+            pub reserved: [u8; 96],
+        }
+
+        /// v1 `Market::LEN`
+        pub const MARKET_V1_LEN: usize = 355;
+
+        #[derive(AnchorSerialize)]
+        pub struct Match {
+            pub match_id: u64,
+            pub market: Pubkey,
+            pub creator: Pubkey,
+            pub match_type: MatchType,
+            pub entry_fee: u64,
+            pub max_players: u8,
+            pub current_players: u8,
+            pub status: MatchStatus,
+            pub start_price: Option<u64>,
+            pub end_price: Option<u64>,
+            pub prediction_window: i64,
+            pub resolution_time: i64,
+            pub winning_side: Option<PredictionSide>,
+            pub total_pot: u64,
+            pub created_at: i64,
+            pub started_at: Option<i64>,
+            pub resolved_at: Option<i64>,
+            pub bump: u8,
+        }
+
+        pub const MATCH_LEN: usize = 8 + 8 + 32 + 32 + 1 + 8 + 1 + 1 + 1 +
+            9 + 9 + 8 + 8 + 2 + 8 + 8 + 9 + 9 + 1;
+
+        /// Match v1: versioned, before the access policy
+        #[derive(AnchorSerialize)]
+        pub struct MatchV1 {
+            pub base: Match,
+            pub version: u8,
+            pub reserved: [u8; 128],
+        }
+
+        pub const MATCH_V1_LEN: usize = MATCH_LEN + 1 + 128;
+
+        /// Match v2: access policy and range buckets, before closest-price tiers
+        #[derive(AnchorSerialize)]
+        pub struct MatchV2 {
+            pub base: Match,
+            pub version: u8,
+            pub access: Option<MatchAccess>,
+            pub bucket_bounds_bps: Vec<i32>,
+            pub bucket_counts: Vec<u8>,
+            pub reserved: [u8; 83],
+        }
+
+        /// v2 `Match::LEN` (winning_side already sized for `Bucket`)
+        pub const MATCH_V2_LEN: usize = 586;
+
+        #[derive(AnchorSerialize)]
+        pub struct PlayerEntry {
+            pub match_account: Pubkey,
+            pub player: Pubkey,
+            pub prediction: Option<PredictionSide>,
+            pub amount_staked: u64,
+            pub prediction_locked_at: Option<i64>,
+            pub claimed: bool,
+            pub winnings: u64,
+            pub bump: u8,
+        }
+
+        pub const PLAYER_ENTRY_LEN: usize = 8 + 32 + 32 + 2 + 8 + 9 + 1 + 8 + 1;
+
+        #[derive(AnchorSerialize)]
+        pub struct UserProfile {
+            pub user: Pubkey,
+            pub username: Option<[u8; 32]>,
+            pub total_matches: u64,
+            pub wins: u64,
+            pub losses: u64,
+            pub total_wagered: u64,
+            pub total_won: u64,
+            pub current_streak: i32,
+            pub best_streak: u32,
+            pub xp: u64,
+            pub level: u16,
+            pub created_at: i64,
+            pub bump: u8,
+        }
+
+        pub const USER_PROFILE_LEN: usize = 8 + 32 + 33 + 8 * 5 + 4 + 4 + 8 + 2 + 8 + 1;
+    }
+
+    /// Build the zero-extended buffer a legacy account has after `realloc`
+    fn legacy_fixture<T: Versioned>(legacy: &impl AnchorSerialize, legacy_len: usize) -> Vec<u8> {
+        let mut data = vec![0u8; legacy_len];
+        data[..8].copy_from_slice(&T::DISCRIMINATOR);
+        let body = legacy.try_to_vec().unwrap();
+        data[8..8 + body.len()].copy_from_slice(&body);
+        data.resize(T::SPACE, 0);
+        data
+    }
+
+    #[test]
+    fn test_migrate_legacy_game_config() {
+        let authority = Pubkey::new_unique();
+        let treasury = Pubkey::new_unique();
+        let mut data = legacy_fixture::<GameConfig>(
+            &legacy::GameConfig {
+                authority,
+                treasury,
+                protocol_fee_bps: 300,
+                total_matches: 42,
+                total_volume: 9_000_000_000,
+                paused: false,
+                bump: 254,
+            },
+            legacy::GAME_CONFIG_LEN,
+        );
+
+        assert_eq!(upgrade_in_place::<GameConfig>(&mut data).unwrap(), (0, GameConfig::VERSION));
+
+        let config = GameConfig::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(config.authority, authority);
+        assert_eq!(config.treasury, treasury);
+        assert_eq!(config.protocol_fee_bps, 300);
+        assert_eq!(config.total_matches, 42);
+        assert_eq!(config.total_volume, 9_000_000_000);
+        assert_eq!(config.bump, 254);
+        assert_eq!(config.version, GameConfig::VERSION);
+    }
+
+    #[test]
+    fn test_migrate_legacy_market() {
+        let mut name = [0u8; 32];
+        name[..7].copy_from_slice(b"SOL/USD");
+        let pyth_price_feed = Pubkey::new_unique();
+        let mut data = legacy_fixture::<Market>(
+            &legacy::Market {
+                market_id: 3,
+                name,
+                description: [0u8; 128],
+                pyth_price_feed,
+                active: true,
+                total_matches: 19,
+                created_at: 1_690_000_000,
+                bump: 251,
+            },
+            legacy::MARKET_LEN,
+        );
+
+        upgrade_in_place::<Market>(&mut data).unwrap();
+
+        let market = Market::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(market.get_name().unwrap(), "SOL/USD");
+        assert_eq!(market.pyth_price_feed, pyth_price_feed);
+        assert_eq!(market.total_matches, 19);
+        assert_eq!(market.version, Market::VERSION);
+    }
+
+    #[test]
+    fn test_migrate_market_v1_to_current() {
+        let mut data = legacy_fixture::<Market>(
+            &legacy::MarketV1 {
+                base: legacy::Market {
+                    market_id: 4,
+                    name: [0u8; 32],
+                    description: [0u8; 128],
+                    pyth_price_feed: Pubkey::new_unique(),
+                    active: true,
+                    total_matches: 88,
+                    created_at: 1_700_000_000,
+                    bump: 249,
+                },
+                version: 1,
+                bucket_bounds_bps: vec![-100, 0, 100],
+                reserved: [0u8; 96],
+            },
+            legacy::MARKET_V1_LEN,
+        );
+
+        assert_eq!(upgrade_in_place::<Market>(&mut data).unwrap(), (1, Market::VERSION));
+
+        let market = Market::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(market.total_matches, 88);
+        assert_eq!(market.bucket_bounds_bps, vec![-100, 0, 100]);
+        assert!(market.templates.is_empty());
+        assert_eq!(market.bump, 249);
+    }
+
+    #[test]
+    fn test_migrate_legacy_match() {
+        let market = Pubkey::new_unique();
+        let mut data = legacy_fixture::<Match>(
+            &legacy::Match {
+                match_id: 7,
+                market,
+                creator: Pubkey::new_unique(),
+                match_type: MatchType::BattleRoyale,
+                entry_fee: 100_000_000,
+                max_players: 5,
+                current_players: 3,
+                status: MatchStatus::Completed,
+                start_price: Some(150_000_000),
+                end_price: Some(151_000_000),
+                prediction_window: 60,
+                resolution_time: 1_700_000_360,
+                winning_side: Some(PredictionSide::Higher),
+                total_pot: 300_000_000,
+                created_at: 1_700_000_000,
+                started_at: Some(1_700_000_030),
+                resolved_at: Some(1_700_000_400),
+                bump: 253,
+            },
+            legacy::MATCH_LEN,
+        );
+
+        assert_eq!(upgrade_in_place::<Match>(&mut data).unwrap(), (0, Match::VERSION));
+
+        let match_account = Match::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(match_account.match_id, 7);
+        assert_eq!(match_account.market, market);
+        assert_eq!(match_account.status, MatchStatus::Completed);
+        assert_eq!(match_account.winning_side, Some(PredictionSide::Higher));
+        assert_eq!(match_account.resolved_at, Some(1_700_000_400));
+        assert_eq!(match_account.bump, 253);
+        assert_eq!(match_account.version, Match::VERSION);
+    }
+
+    #[test]
+    fn test_migrate_legacy_match_with_unset_options() {
+        // Open matches serialize shorter than LEN; the tail is still zeroed
+        let mut data = legacy_fixture::<Match>(
+            &legacy::Match {
+                match_id: 1,
+                market: Pubkey::new_unique(),
+                creator: Pubkey::new_unique(),
+                match_type: MatchType::FlashDuel,
+                entry_fee: 10_000_000,
+                max_players: 2,
+                current_players: 1,
+                status: MatchStatus::Open,
+                start_price: None,
+                end_price: None,
+                prediction_window: 30,
+                resolution_time: 1_700_000_090,
+                winning_side: None,
+                total_pot: 10_000_000,
+                created_at: 1_700_000_000,
+                started_at: None,
+                resolved_at: None,
+                bump: 255,
+            },
+            legacy::MATCH_LEN,
+        );
+
+        upgrade_in_place::<Match>(&mut data).unwrap();
+
+        let match_account = Match::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(match_account.status, MatchStatus::Open);
+        assert_eq!(match_account.start_price, None);
+        assert_eq!(match_account.bump, 255);
+        assert_eq!(match_account.version, Match::VERSION);
+    }
+
+    #[test]
+    fn test_migrate_match_v1_to_current() {
+        let creator = Pubkey::new_unique();
+        let mut data = legacy_fixture::<Match>(
+            &legacy::MatchV1 {
+                base: legacy::Match {
+                    match_id: 12,
+                    market: Pubkey::new_unique(),
+                    creator,
+                    match_type: MatchType::FlashDuel,
+                    entry_fee: 20_000_000,
+                    max_players: 2,
+                    current_players: 2,
+                    status: MatchStatus::InProgress,
+                    start_price: Some(150_000_000),
+                    end_price: None,
+                    prediction_window: 60,
+                    resolution_time: 1_700_000_360,
+                    winning_side: None,
+                    total_pot: 40_000_000,
+                    created_at: 1_700_000_000,
+                    started_at: Some(1_700_000_020),
+                    resolved_at: None,
+                    bump: 254,
+                },
+                version: 1,
+                reserved: [0u8; 128],
+            },
+            legacy::MATCH_V1_LEN,
+        );
+
+        assert_eq!(upgrade_in_place::<Match>(&mut data).unwrap(), (1, Match::VERSION));
+
+        let match_account = Match::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(match_account.creator, creator);
+        assert_eq!(match_account.status, MatchStatus::InProgress);
+        assert_eq!(match_account.access, None);
+        assert!(!match_account.is_private());
+    }
+
+    #[test]
+    fn test_migrate_match_v2_to_current() {
+        let invited = Pubkey::new_unique();
+        let mut data = legacy_fixture::<Match>(
+            &legacy::MatchV2 {
+                base: legacy::Match {
+                    match_id: 31,
+                    market: Pubkey::new_unique(),
+                    creator: Pubkey::new_unique(),
+                    match_type: MatchType::BattleRoyale,
+                    entry_fee: 10_000_000,
+                    max_players: 5,
+                    current_players: 2,
+                    status: MatchStatus::Completed,
+                    start_price: Some(100_000_000),
+                    end_price: Some(101_000_000),
+                    prediction_window: 60,
+                    resolution_time: 1_700_000_360,
+                    winning_side: Some(PredictionSide::Bucket(2)),
+                    total_pot: 20_000_000,
+                    created_at: 1_700_000_000,
+                    started_at: Some(1_700_000_020),
+                    resolved_at: Some(1_700_000_400),
+                    bump: 250,
+                },
+                version: 2,
+                access: Some(MatchAccess::Allowlist { players: vec![invited] }),
+                bucket_bounds_bps: vec![-50, 50],
+                bucket_counts: vec![1, 0, 1],
+                reserved: [0u8; 83],
+            },
+            legacy::MATCH_V2_LEN,
+        );
+
+        assert_eq!(upgrade_in_place::<Match>(&mut data).unwrap(), (2, Match::VERSION));
+
+        let match_account = Match::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(match_account.winning_side, Some(PredictionSide::Bucket(2)));
+        assert!(match_account.admits(&invited, None));
+        assert_eq!(match_account.bucket_counts, vec![1, 0, 1]);
+        assert!(!match_account.is_closest_price_mode());
+        assert!(match_account.targets.is_empty());
+    }
+
+    #[test]
+    fn test_migrate_legacy_player_entry() {
+        let player = Pubkey::new_unique();
+        let mut data = legacy_fixture::<PlayerEntry>(
+            &legacy::PlayerEntry {
+                match_account: Pubkey::new_unique(),
+                player,
+                prediction: Some(PredictionSide::Lower),
+                amount_staked: 50_000_000,
+                prediction_locked_at: Some(1_700_000_010),
+                claimed: true,
+                winnings: 97_000_000,
+                bump: 250,
+            },
+            legacy::PLAYER_ENTRY_LEN,
+        );
+
+        upgrade_in_place::<PlayerEntry>(&mut data).unwrap();
+
+        let entry = PlayerEntry::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(entry.player, player);
+        assert_eq!(entry.prediction, Some(PredictionSide::Lower));
+        assert!(entry.claimed);
+        assert_eq!(entry.winnings, 97_000_000);
+        assert_eq!(entry.version, PlayerEntry::VERSION);
+    }
+
+    #[test]
+    fn test_migrate_legacy_user_profile() {
+        let mut data = legacy_fixture::<UserProfile>(
+            &legacy::UserProfile {
+                user: Pubkey::new_unique(),
+                username: None,
+                total_matches: 12,
+                wins: 8,
+                losses: 4,
+                total_wagered: 1_200_000_000,
+                total_won: 1_500_000_000,
+                current_streak: -2,
+                best_streak: 5,
+                xp: 2_400,
+                level: 1,
+                created_at: 1_690_000_000,
+                bump: 249,
+            },
+            legacy::USER_PROFILE_LEN,
+        );
+
+        upgrade_in_place::<UserProfile>(&mut data).unwrap();
+
+        let profile = UserProfile::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(profile.username, None);
+        assert_eq!(profile.wins, 8);
+        assert_eq!(profile.current_streak, -2);
+        assert_eq!(profile.bump, 249);
+        assert_eq!(profile.version, UserProfile::VERSION);
+    }
+
+    #[test]
+    fn test_migrate_rejects_current_version() {
+        let mut data = legacy_fixture::<PlayerEntry>(
+            &legacy::PlayerEntry {
+                match_account: Pubkey::new_unique(),
+                player: Pubkey::new_unique(),
+                prediction: None,
+                amount_staked: 10_000_000,
+                prediction_locked_at: None,
+                claimed: false,
+                winnings: 0,
+                bump: 255,
+            },
+            legacy::PLAYER_ENTRY_LEN,
+        );
+
+        upgrade_in_place::<PlayerEntry>(&mut data).unwrap();
+        assert!(upgrade_in_place::<PlayerEntry>(&mut data).is_err());
+    }
+}
@@ -1,3 +1,29 @@
 pub mod pyth;
+pub mod migration;
+pub mod bracket;
+pub mod buckets;
+pub mod closest_price;
+pub mod sealed;
+pub mod elimination;
+pub mod series;
+pub mod schedule;
+pub mod escrow;
+pub mod limits;
+pub mod fee_tiers;
+pub mod fee_split;
+pub mod jackpot;
 
 pub use pyth::*;
+pub use migration::*;
+pub use bracket::*;
+pub use buckets::*;
+pub use closest_price::*;
+pub use sealed::*;
+pub use elimination::*;
+pub use series::*;
+pub use schedule::*;
+pub use escrow::*;
+pub use limits::*;
+pub use fee_tiers::*;
+pub use fee_split::*;
+pub use jackpot::*;
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use pyth_sdk_solana::load_price_feed_from_account_info;
 use std::mem::transmute;
-use crate::ErrorCode;
+use crate::{ErrorCode, Market, UsdEntryFee};
 
 /// Maximum age for price data (30 seconds)
 pub const MAX_PRICE_AGE_SECONDS: u64 = 30;
@@ -225,6 +225,105 @@ pub fn compare_prices(start_price: u64, end_price: u64) -> PriceComparison {
     }
 }
 
+/// Compare two markets' percent change over the same window
+/// Higher = A outperformed B, Lower = B outperformed A
+pub fn compare_performance(
+    start_a: u64,
+    end_a: u64,
+    start_b: u64,
+    end_b: u64,
+) -> PriceComparison {
+    // end_a / start_a vs end_b / start_b, cross-multiplied to stay exact
+    let ratio_a = end_a as u128 * start_b as u128;
+    let ratio_b = end_b as u128 * start_a as u128;
+
+    if ratio_a > ratio_b {
+        PriceComparison::Higher
+    } else if ratio_a < ratio_b {
+        PriceComparison::Lower
+    } else {
+        PriceComparison::Equal
+    }
+}
+
+/// Price of a relative-performance match's second market
+/// Both accounts are required and must match the market recorded on the match
+pub fn get_second_market_price(
+    expected_market: &Pubkey,
+    market_b: Option<&Account<Market>>,
+    price_update_b: Option<&AccountInfo>,
+    clock: &Clock,
+) -> Result<u64> {
+    let market_b = market_b.ok_or(ErrorCode::InvalidSecondMarket)?;
+    let price_update_b = price_update_b.ok_or(ErrorCode::InvalidSecondMarket)?;
+
+    require_keys_eq!(market_b.key(), *expected_market, ErrorCode::InvalidSecondMarket);
+    require_keys_eq!(price_update_b.key(), market_b.pyth_price_feed, ErrorCode::InvalidPythAccount);
+
+    get_price_for_comparison(price_update_b, &market_b.pyth_price_feed.to_string(), clock)
+}
+
+/// Lamports worth `cents` US cents at a SOL/USD price normalized to 6 decimals
+/// Rounds up so a pegged stake is never worth less than its USD fee
+pub fn usd_cents_to_lamports(cents: u64, sol_usd_price: u64) -> Result<u64> {
+    require!(sol_usd_price > 0, ErrorCode::PriceUnavailable);
+
+    // cents * 10^4 micro-USD per cent * 10^9 lamports per SOL / micro-USD per SOL
+    let numerator = cents as u128 * 10_000 * 1_000_000_000;
+    let lamports = numerator.div_ceil(sol_usd_price as u128);
+
+    u64::try_from(lamports).map_err(|_| ErrorCode::ArithmeticOverflow.into())
+}
+
+/// Convert a USD-pegged entry fee to lamports at the current SOL/USD price
+/// The price account must be the feed recorded on the match, and the result
+/// must not exceed the payer's `max_lamports` slippage bound
+pub fn get_usd_entry_fee_lamports(
+    usd_entry_fee: &UsdEntryFee,
+    price_update: Option<&AccountInfo>,
+    max_lamports: Option<u64>,
+    clock: &Clock,
+) -> Result<u64> {
+    let price_update = price_update.ok_or(ErrorCode::UsdPricingUnavailable)?;
+    require_keys_eq!(price_update.key(), usd_entry_fee.price_feed, ErrorCode::InvalidPythAccount);
+
+    let sol_usd_price = get_price_for_comparison(price_update, &usd_entry_fee.price_feed.to_string(), clock)?;
+    let lamports = usd_cents_to_lamports(usd_entry_fee.cents, sol_usd_price)?;
+
+    require!(
+        max_lamports.is_some_and(|max| lamports <= max),
+        ErrorCode::EntryFeeSlippageExceeded
+    );
+
+    Ok(lamports)
+}
+
+/// Stake-weighted share of a USD-pegged match payout, where stakes differ
+/// per player: the pool of the winner's group (`equal_share` for each of its
+/// `group_size` members) split in proportion to `amount_staked`
+pub fn stake_weighted_share(equal_share: u64, group_size: u64, amount_staked: u64, group_stake: u64) -> u64 {
+    if group_stake == 0 {
+        return 0;
+    }
+    (equal_share as u128 * group_size as u128 * amount_staked as u128 / group_stake as u128) as u64
+}
+
+/// Signed price change from start to end in basis points of the start price
+pub fn price_change_bps(start_price: u64, end_price: u64) -> i64 {
+    if start_price == 0 {
+        return 0;
+    }
+
+    let change = end_price as i128 - start_price as i128;
+    (change * 10_000 / start_price as i128) as i64
+}
+
+/// Check if the move from start to end exceeds `threshold_bps` in either direction
+pub fn moved_beyond(start_price: u64, end_price: u64, threshold_bps: u16) -> bool {
+    // |end - start| / start > threshold / 10_000, cross-multiplied to stay exact
+    start_price.abs_diff(end_price) as u128 * 10_000 > threshold_bps as u128 * start_price as u128
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -295,4 +394,58 @@ mod tests {
         assert_eq!(compare_prices(200, 100), PriceComparison::Lower);
         assert_eq!(compare_prices(100, 100), PriceComparison::Equal);
     }
+
+    #[test]
+    fn test_compare_performance() {
+        // A +2%, B +1%
+        assert_eq!(compare_performance(100, 102, 3_000, 3_030), PriceComparison::Higher);
+        // A -1%, B +0.5%
+        assert_eq!(compare_performance(50_000, 49_500, 2_000, 2_010), PriceComparison::Lower);
+        // Both +10%, different price scales
+        assert_eq!(compare_performance(10, 11, 60_000, 66_000), PriceComparison::Equal);
+    }
+
+    #[test]
+    fn test_price_change_bps() {
+        assert_eq!(price_change_bps(100_000_000, 102_000_000), 200);
+        assert_eq!(price_change_bps(100_000_000, 99_500_000), -50);
+        assert_eq!(price_change_bps(100_000_000, 100_000_000), 0);
+        assert_eq!(price_change_bps(0, 100), 0);
+    }
+
+    #[test]
+    fn test_usd_cents_to_lamports() {
+        // $5.00 at $100/SOL = 0.05 SOL
+        assert_eq!(usd_cents_to_lamports(500, 100_000_000).unwrap(), 50_000_000);
+        // $1.00 at $150/SOL rounds up to the next lamport
+        assert_eq!(usd_cents_to_lamports(100, 150_000_000).unwrap(), 6_666_667);
+        // Cheaper SOL means more lamports for the same fee
+        assert!(usd_cents_to_lamports(100, 50_000_000).unwrap() > usd_cents_to_lamports(100, 100_000_000).unwrap());
+        assert!(usd_cents_to_lamports(100, 0).is_err());
+        assert!(usd_cents_to_lamports(u64::MAX, 1).is_err());
+    }
+
+    #[test]
+    fn test_stake_weighted_share() {
+        // Two winners sharing 300: one staked 100, the other 200
+        assert_eq!(stake_weighted_share(150, 2, 100, 300), 100);
+        assert_eq!(stake_weighted_share(150, 2, 200, 300), 200);
+        // Equal stakes keep equal shares
+        assert_eq!(stake_weighted_share(150, 2, 100, 200), 150);
+        // Shares never add up to more than the group's pool
+        let shares: u64 = [1, 1, 1].iter().map(|&stake| stake_weighted_share(33, 3, stake, 3)).sum();
+        assert!(shares <= 99);
+        assert_eq!(stake_weighted_share(150, 2, 100, 0), 0);
+    }
+
+    #[test]
+    fn test_moved_beyond() {
+        assert!(moved_beyond(100_000_000, 101_500_000, 100));
+        assert!(moved_beyond(100_000_000, 98_000_000, 100));
+        // Exactly at the threshold stays within
+        assert!(!moved_beyond(100_000_000, 101_000_000, 100));
+        assert!(!moved_beyond(100_000_000, 99_500_000, 100));
+        // Sub-bps moves past the threshold still count
+        assert!(moved_beyond(100_000_000, 101_000_500, 100));
+    }
 }
@@ -0,0 +1,32 @@
+/// Next template spawn time strictly after `now`, staying on the cadence grid
+/// anchored at `next_spawn_at` (missed slots are skipped, not replayed)
+pub fn next_spawn_slot(next_spawn_at: i64, cadence: i64, now: i64) -> i64 {
+    if now < next_spawn_at {
+        return next_spawn_at;
+    }
+
+    let elapsed_slots = (now - next_spawn_at) / cadence + 1;
+    next_spawn_at + elapsed_slots * cadence
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_on_time_spawn_advances_one_slot() {
+        assert_eq!(next_spawn_slot(1_000, 300, 1_000), 1_300);
+        assert_eq!(next_spawn_slot(1_000, 300, 1_299), 1_300);
+    }
+
+    #[test]
+    fn test_late_spawn_skips_missed_slots() {
+        assert_eq!(next_spawn_slot(1_000, 300, 1_300), 1_600);
+        assert_eq!(next_spawn_slot(1_000, 300, 2_050), 2_200);
+    }
+
+    #[test]
+    fn test_future_slot_is_kept() {
+        assert_eq!(next_spawn_slot(5_000, 300, 1_000), 5_000);
+    }
+}
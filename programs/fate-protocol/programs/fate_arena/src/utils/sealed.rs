@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::PredictionSide;
+
+/// Commitment for a sealed prediction: SHA-256(borsh(prediction) || salt)
+pub fn prediction_commitment(prediction: &PredictionSide, salt: &[u8; 32]) -> Result<[u8; 32]> {
+    let encoded = prediction.try_to_vec()?;
+    Ok(hashv(&[&encoded, salt]).to_bytes())
+}
+
+/// Refund of an entry in a sealed match that ended without winners
+/// Unrevealed stakes stay forfeit: players who revealed split the whole pot
+/// by stake. If nobody revealed (or reveals weren't tallied), every stake
+/// goes back to its owner
+pub fn sealed_refund(amount_staked: u64, revealed: bool, revealed_stake: Option<u64>, total_pot: u64) -> u64 {
+    match revealed_stake {
+        Some(revealed_stake) if revealed_stake > 0 => {
+            if revealed {
+                (amount_staked as u128 * total_pot as u128 / revealed_stake as u128) as u64
+            } else {
+                0
+            }
+        },
+        _ => amount_staked,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commitment_matches_reveal() {
+        let salt = [7u8; 32];
+        let commitment = prediction_commitment(&PredictionSide::Higher, &salt).unwrap();
+
+        assert_eq!(prediction_commitment(&PredictionSide::Higher, &salt).unwrap(), commitment);
+        assert_ne!(prediction_commitment(&PredictionSide::Lower, &salt).unwrap(), commitment);
+        assert_ne!(prediction_commitment(&PredictionSide::Higher, &[8u8; 32]).unwrap(), commitment);
+    }
+
+    #[test]
+    fn test_commitment_binds_payload() {
+        let salt = [1u8; 32];
+
+        assert_ne!(
+            prediction_commitment(&PredictionSide::TargetPrice(100), &salt).unwrap(),
+            prediction_commitment(&PredictionSide::TargetPrice(101), &salt).unwrap()
+        );
+        assert_ne!(
+            prediction_commitment(&PredictionSide::Bucket(0), &salt).unwrap(),
+            prediction_commitment(&PredictionSide::Bucket(1), &salt).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_sealed_refund_splits_pot_among_revealers() {
+        // 3 x 100 staked, two revealed: they share the forfeited stake
+        assert_eq!(sealed_refund(100, true, Some(200), 300), 150);
+        assert_eq!(sealed_refund(100, false, Some(200), 300), 0);
+
+        // Larger stakes take a larger part
+        assert_eq!(sealed_refund(300, true, Some(400), 500), 375);
+        assert_eq!(sealed_refund(100, true, Some(400), 500), 125);
+    }
+
+    #[test]
+    fn test_sealed_refund_without_reveals_returns_stakes() {
+        assert_eq!(sealed_refund(100, false, Some(0), 300), 100);
+        assert_eq!(sealed_refund(100, false, None, 300), 100);
+        assert_eq!(sealed_refund(100, true, None, 300), 100);
+    }
+}
@@ -0,0 +1,71 @@
+use crate::PredictionSide;
+use crate::utils::bracket::{first_advances, Contender};
+
+/// Round wins needed to take a best-of-`best_of` series
+pub fn wins_needed(best_of: u8) -> u8 {
+    best_of / 2 + 1
+}
+
+/// Decide one series round
+/// Returns Some(true) if player A takes it, Some(false) for player B, and
+/// None if neither player locked a prediction (the round is replayed)
+/// Otherwise ties break on lock time, as in tournament brackets
+pub fn decide_round(
+    a: &Contender,
+    b: &Contender,
+    resolved: bool,
+    winning_side: Option<PredictionSide>,
+) -> Option<bool> {
+    if !resolved || (a.prediction.is_none() && b.prediction.is_none()) {
+        return None;
+    }
+
+    Some(first_advances(a, b, resolved, winning_side))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contender(seed: u8, prediction: Option<PredictionSide>, locked_at: Option<i64>) -> Contender {
+        Contender { seed, prediction, locked_at }
+    }
+
+    #[test]
+    fn test_wins_needed() {
+        assert_eq!(wins_needed(1), 1);
+        assert_eq!(wins_needed(3), 2);
+        assert_eq!(wins_needed(5), 3);
+        assert_eq!(wins_needed(7), 4);
+    }
+
+    #[test]
+    fn test_correct_call_takes_round() {
+        let a = contender(0, Some(PredictionSide::Higher), Some(100));
+        let b = contender(1, Some(PredictionSide::Lower), Some(90));
+
+        assert_eq!(decide_round(&a, &b, true, Some(PredictionSide::Higher)), Some(true));
+        assert_eq!(decide_round(&a, &b, true, Some(PredictionSide::Lower)), Some(false));
+    }
+
+    #[test]
+    fn test_same_call_breaks_on_lock_time() {
+        let a = contender(0, Some(PredictionSide::Lower), Some(120));
+        let b = contender(1, Some(PredictionSide::Lower), Some(110));
+
+        assert_eq!(decide_round(&a, &b, true, Some(PredictionSide::Lower)), Some(false));
+    }
+
+    #[test]
+    fn test_empty_round_is_replayed() {
+        let a = contender(0, None, None);
+        let b = contender(1, None, None);
+
+        assert_eq!(decide_round(&a, &b, true, Some(PredictionSide::Higher)), None);
+        assert_eq!(decide_round(&a, &b, false, None), None);
+
+        // A lone caller takes the round even if wrong
+        let c = contender(1, Some(PredictionSide::Lower), Some(100));
+        assert_eq!(decide_round(&a, &c, true, Some(PredictionSide::Higher)), Some(false));
+    }
+}
@@ -49,4 +49,7 @@ pub enum ErrorCode {
 
     #[msg("Cannot cancel proposal after voting has started")]
     CannotCancelAfterVotingStarted,
+
+    #[msg("Account is already at the current version")]
+    AccountAlreadyMigrated,
 }
@@ -96,6 +96,7 @@ pub fn handler(ctx: Context<CreateProposal>, params: CreateProposalParams) -> Re
     proposal.voting_ends_at = clock.unix_timestamp + config.voting_period;
     proposal.executed_at = None;
     proposal.bump = ctx.bumps.proposal;
+    proposal.version = Proposal::VERSION;
 
     // Increment counter
     config.total_proposals = config.total_proposals.checked_add(1)
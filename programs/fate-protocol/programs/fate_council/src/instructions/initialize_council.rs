@@ -41,6 +41,7 @@ pub fn handler(ctx: Context<InitializeCouncil>, params: InitializeCouncilParams)
     config.total_proposals = 0;
     config.proposer_bonus_bps = params.proposer_bonus_bps.unwrap_or(CouncilConfig::DEFAULT_PROPOSER_BONUS_BPS);
     config.bump = ctx.bumps.config;
+    config.version = CouncilConfig::VERSION;
 
     msg!("Council initialized with futarchy governance");
     msg!("Proposal stake: {} lamports", config.proposal_stake);
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::utils::migration::{migrate_account, Versioned};
+
+/// Shared by every `migrate_*` instruction. Anyone can pay to upgrade an
+/// account; the handler checks owner and discriminator for the target type.
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// CHECK: Owner and discriminator validated in handler
+    #[account(mut)]
+    pub account: AccountInfo<'info>,
+
+    /// Pays rent for the extra space
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<T: Versioned>(ctx: Context<MigrateAccount>) -> Result<()> {
+    let (from_version, to_version) = migrate_account::<T>(
+        &ctx.accounts.account,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit!(AccountMigrated {
+        account: ctx.accounts.account.key(),
+        from_version,
+        to_version,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+}
@@ -5,6 +5,7 @@ pub mod resolve_proposal;
 pub mod execute_proposal;
 pub mod claim_vote_tokens;
 pub mod cancel_proposal;
+pub mod migrate_account;
 
 pub use initialize_council::*;
 pub use create_proposal::*;
@@ -13,3 +14,4 @@ pub use resolve_proposal::*;
 pub use execute_proposal::*;
 pub use claim_vote_tokens::*;
 pub use cancel_proposal::*;
+pub use migrate_account::*;
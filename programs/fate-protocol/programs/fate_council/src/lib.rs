@@ -4,6 +4,7 @@ pub mod constants;
 pub mod errors;
 pub mod instructions;
 pub mod state;
+pub mod utils;
 
 use instructions::*;
 pub use constants::*;
@@ -50,4 +51,14 @@ pub mod fate_council {
     pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
         instructions::cancel_proposal::handler(ctx)
     }
+
+    /// Upgrade the CouncilConfig account to the current layout
+    pub fn migrate_council_config(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<CouncilConfig>(ctx)
+    }
+
+    /// Upgrade a Proposal account to the current layout
+    pub fn migrate_proposal(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<Proposal>(ctx)
+    }
 }
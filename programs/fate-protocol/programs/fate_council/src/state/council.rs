@@ -23,6 +23,12 @@ pub struct CouncilConfig {
 
     /// Bump seed for PDA
     pub bump: u8,
+
+    /// Account layout version
+    pub version: u8,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 64],
 }
 
 impl CouncilConfig {
@@ -33,7 +39,12 @@ impl CouncilConfig {
         8 + // voting_period
         8 + // total_proposals
         2 + // proposer_bonus_bps
-        1; // bump
+        1 + // bump
+        1 + // version
+        64; // reserved
+
+    /// Current account layout version
+    pub const VERSION: u8 = 1;
 
     /// Default voting period: 48 hours
     pub const DEFAULT_VOTING_PERIOD: i64 = 48 * 60 * 60;
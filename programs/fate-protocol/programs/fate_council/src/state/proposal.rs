@@ -59,6 +59,12 @@ pub struct Proposal {
 
     /// Bump seed for PDA
     pub bump: u8,
+
+    /// Account layout version
+    pub version: u8,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 64],
 }
 
 impl Proposal {
@@ -76,7 +82,12 @@ impl Proposal {
         8 + // created_at
         8 + // voting_ends_at
         1 + 8 + // executed_at (Option)
-        1; // bump
+        1 + // bump
+        1 + // version
+        64; // reserved
+
+    /// Current account layout version
+    pub const VERSION: u8 = 1;
 
     pub const MAX_NAME_LEN: usize = 64;
     pub const MAX_DESCRIPTION_LEN: usize = 200;
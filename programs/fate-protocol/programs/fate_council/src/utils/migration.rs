@@ -0,0 +1,250 @@
+use anchor_lang::prelude::*;
+use anchor_lang::{system_program, Discriminator};
+use crate::errors::ErrorCode as CouncilError;
+use crate::state::*;
+
+/// Program accounts that carry a layout version and can be upgraded in place.
+///
+/// New fields are appended after `version` and carved out of `reserved`, so an
+/// account written with an older layout always deserializes once its data has
+/// been zero-extended to `SPACE` bytes.
+pub trait Versioned: AccountSerialize + AccountDeserialize + Owner + Discriminator {
+    /// Allocated size of the current layout (including discriminator)
+    const SPACE: usize;
+
+    /// Current layout version
+    const CURRENT_VERSION: u8;
+
+    fn version(&self) -> u8;
+
+    fn set_version(&mut self, version: u8);
+}
+
+macro_rules! impl_versioned {
+    ($($account:ty),* $(,)?) => {
+        $(
+            impl Versioned for $account {
+                const SPACE: usize = <$account>::LEN;
+                const CURRENT_VERSION: u8 = <$account>::VERSION;
+
+                fn version(&self) -> u8 {
+                    self.version
+                }
+
+                fn set_version(&mut self, version: u8) {
+                    self.version = version;
+                }
+            }
+        )*
+    };
+}
+
+impl_versioned!(CouncilConfig, Proposal);
+
+/// Grow `account` to the current layout size and bump its version.
+/// The payer tops up rent for the extra space.
+/// Returns (from_version, to_version).
+pub fn migrate_account<'info, T: Versioned>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<(u8, u8)> {
+    require_keys_eq!(
+        *account.owner,
+        T::owner(),
+        anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram
+    );
+
+    {
+        let data = account.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == T::DISCRIMINATOR,
+            anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+        );
+    }
+
+    if account.data_len() < T::SPACE {
+        let required_lamports = Rent::get()?.minimum_balance(T::SPACE);
+        let top_up = required_lamports.saturating_sub(account.lamports());
+
+        if top_up > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    system_program::Transfer {
+                        from: payer.clone(),
+                        to: account.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+
+        account.realloc(T::SPACE, true)?;
+    }
+
+    let mut data = account.try_borrow_mut_data()?;
+    upgrade_in_place::<T>(&mut data)
+}
+
+/// Rewrite an account buffer that is already `T::SPACE` bytes long
+/// with the current layout version.
+/// Returns (from_version, to_version).
+pub fn upgrade_in_place<T: Versioned>(data: &mut [u8]) -> Result<(u8, u8)> {
+    let mut account = T::try_deserialize(&mut &data[..])?;
+
+    let from_version = account.version();
+    require!(
+        from_version < T::CURRENT_VERSION,
+        CouncilError::AccountAlreadyMigrated
+    );
+
+    account.set_version(T::CURRENT_VERSION);
+
+    let mut writer: &mut [u8] = data;
+    account.try_serialize(&mut writer)?;
+
+    Ok((from_version, T::CURRENT_VERSION))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Account layouts as written before versioning was introduced
+    mod legacy {
+        use super::*;
+
+        #[derive(AnchorSerialize)]
+        pub struct CouncilConfig {
+            pub authority: Pubkey,
+            pub fate_arena_program: Pubkey,
+            pub proposal_stake: u64,
+            pub voting_period: i64,
+            pub total_proposals: u64,
+            pub proposer_bonus_bps: u16,
+            pub bump: u8,
+        }
+
+        pub const COUNCIL_CONFIG_LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 2 + 1;
+
+        #[derive(AnchorSerialize)]
+        pub struct Proposal {
+            pub proposal_id: u64,
+            pub proposer: Pubkey,
+            pub market_name: [u8; 64],
+            pub market_description: [u8; 200],
+            pub pyth_price_feed: Pubkey,
+            pub status: ProposalStatus,
+            pub pass_pool: u64,
+            pub fail_pool: u64,
+            pub pass_price: u64,
+            pub fail_price: u64,
+            pub created_at: i64,
+            pub voting_ends_at: i64,
+            pub executed_at: Option<i64>,
+            pub bump: u8,
+        }
+
+        pub const PROPOSAL_LEN: usize = 8 + 8 + 32 + 64 + 200 + 32 + 1 +
+            8 + 8 + 8 + 8 + 8 + 8 + 9 + 1;
+    }
+
+    /// Build the zero-extended buffer a legacy account has after `realloc`
+    fn legacy_fixture<T: Versioned>(legacy: &impl AnchorSerialize, legacy_len: usize) -> Vec<u8> {
+        let mut data = vec![0u8; legacy_len];
+        data[..8].copy_from_slice(&T::DISCRIMINATOR);
+        let body = legacy.try_to_vec().unwrap();
+        data[8..8 + body.len()].copy_from_slice(&body);
+        data.resize(T::SPACE, 0);
+        data
+    }
+
+    #[test]
+    fn test_migrate_legacy_council_config() {
+        let authority = Pubkey::new_unique();
+        let fate_arena_program = Pubkey::new_unique();
+        let mut data = legacy_fixture::<CouncilConfig>(
+            &legacy::CouncilConfig {
+                authority,
+                fate_arena_program,
+                proposal_stake: CouncilConfig::DEFAULT_PROPOSAL_STAKE,
+                voting_period: CouncilConfig::DEFAULT_VOTING_PERIOD,
+                total_proposals: 4,
+                proposer_bonus_bps: CouncilConfig::DEFAULT_PROPOSER_BONUS_BPS,
+                bump: 254,
+            },
+            legacy::COUNCIL_CONFIG_LEN,
+        );
+
+        assert_eq!(
+            upgrade_in_place::<CouncilConfig>(&mut data).unwrap(),
+            (0, CouncilConfig::VERSION)
+        );
+
+        let config = CouncilConfig::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(config.authority, authority);
+        assert_eq!(config.fate_arena_program, fate_arena_program);
+        assert_eq!(config.total_proposals, 4);
+        assert_eq!(config.proposer_bonus_bps, CouncilConfig::DEFAULT_PROPOSER_BONUS_BPS);
+        assert_eq!(config.bump, 254);
+        assert_eq!(config.version, CouncilConfig::VERSION);
+    }
+
+    #[test]
+    fn test_migrate_legacy_proposal() {
+        let proposer = Pubkey::new_unique();
+        let mut market_name = [0u8; 64];
+        market_name[..7].copy_from_slice(b"BTC/USD");
+        let mut data = legacy_fixture::<Proposal>(
+            &legacy::Proposal {
+                proposal_id: 2,
+                proposer,
+                market_name,
+                market_description: [0u8; 200],
+                pyth_price_feed: Pubkey::new_unique(),
+                status: ProposalStatus::Passed,
+                pass_pool: 3_000_000_000,
+                fail_pool: 1_000_000_000,
+                pass_price: 2500,
+                fail_price: 7500,
+                created_at: 1_700_000_000,
+                voting_ends_at: 1_700_172_800,
+                executed_at: None,
+                bump: 252,
+            },
+            legacy::PROPOSAL_LEN,
+        );
+
+        upgrade_in_place::<Proposal>(&mut data).unwrap();
+
+        let proposal = Proposal::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(proposal.proposer, proposer);
+        assert_eq!(proposal.market_name, market_name);
+        assert_eq!(proposal.status, ProposalStatus::Passed);
+        assert_eq!(proposal.pass_pool, 3_000_000_000);
+        assert_eq!(proposal.executed_at, None);
+        assert_eq!(proposal.bump, 252);
+        assert_eq!(proposal.version, Proposal::VERSION);
+        assert!(proposal.has_passed());
+    }
+
+    #[test]
+    fn test_migrate_rejects_current_version() {
+        let mut data = legacy_fixture::<CouncilConfig>(
+            &legacy::CouncilConfig {
+                authority: Pubkey::new_unique(),
+                fate_arena_program: Pubkey::new_unique(),
+                proposal_stake: 1,
+                voting_period: 1,
+                total_proposals: 0,
+                proposer_bonus_bps: 0,
+                bump: 255,
+            },
+            legacy::COUNCIL_CONFIG_LEN,
+        );
+
+        upgrade_in_place::<CouncilConfig>(&mut data).unwrap();
+        assert!(upgrade_in_place::<CouncilConfig>(&mut data).is_err());
+    }
+}
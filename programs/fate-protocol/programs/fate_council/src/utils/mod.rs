@@ -0,0 +1,3 @@
+pub mod migration;
+
+pub use migration::*;